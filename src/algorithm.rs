@@ -6,6 +6,7 @@
 
 use super::graph::Node;
 use crate::signal_handling::received_ctrl_c;
+use std::sync::Mutex;
 use std::time::{Duration, Instant};
 
 /// [`IterativeAlgorithm`] provides a consistent interface to execute all our algorithms. Observe
@@ -87,6 +88,31 @@ pub trait IterativeAlgorithm {
         let start = Instant::now();
         self.run_while(|_| start.elapsed() < timeout);
     }
+
+    /// Same as [`IterativeAlgorithm::run_until_timeout`], but after each step the best known
+    /// solution is stored in `shared` if it is smaller than the one stored there. This keeps the
+    /// solution written by `signal_handling::install_solution_dump_handler` and
+    /// [`crate::signal_handling::start_checkpointing`] up to date.
+    fn run_until_timeout_sharing(&mut self, timeout: Duration, shared: &Mutex<Option<Vec<Node>>>) {
+        let start = Instant::now();
+        self.share_best_known_solution(shared);
+        self.run_while(|algo| {
+            algo.share_best_known_solution(shared);
+            start.elapsed() < timeout
+        });
+    }
+
+    /// Stores the best known solution in `shared` if it is smaller than the one stored there (or
+    /// if there is none yet).
+    fn share_best_known_solution(&mut self, shared: &Mutex<Option<Vec<Node>>>) {
+        if let Some(solution) = self.best_known_solution() {
+            // a panic while holding the lock must not prevent later updates
+            let mut stored = shared.lock().unwrap_or_else(|e| e.into_inner());
+            if stored.as_ref().is_none_or(|s| solution.len() < s.len()) {
+                *stored = Some(solution.to_vec());
+            }
+        }
+    }
 }
 
 /// [`TerminatingIterativeAlgorithm`] is a marker trait, i.e. to adopt it, you give an empty `impl`
@@ -118,3 +144,55 @@ pub trait TerminatingIterativeAlgorithm: IterativeAlgorithm {
         Some(self.best_known_solution()?.to_vec())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Starts with all `n` nodes as solution and drops one per step until a single node is left
+    struct Shrinking {
+        solution: Vec<Node>,
+        steps: usize,
+    }
+
+    impl IterativeAlgorithm for Shrinking {
+        fn execute_step(&mut self) {
+            self.solution.pop();
+            self.steps += 1;
+        }
+
+        fn is_completed(&self) -> bool {
+            self.solution.len() <= 1
+        }
+
+        fn best_known_solution(&mut self) -> Option<&[Node]> {
+            Some(&self.solution)
+        }
+    }
+
+    #[test]
+    fn run_until_timeout_sharing() {
+        let shared = Mutex::new(None);
+        let mut algo = Shrinking {
+            solution: (0..10).collect(),
+            steps: 0,
+        };
+        algo.run_until_timeout_sharing(Duration::from_secs(3600), &shared);
+        assert_eq!(algo.steps, 9);
+        assert_eq!(*shared.lock().unwrap(), Some(vec![0]));
+
+        // a worse solution does not replace a better one
+        let mut algo = Shrinking {
+            solution: (0..5).collect(),
+            steps: 0,
+        };
+        algo.run_until_timeout_sharing(Duration::ZERO, &shared);
+        assert_eq!(algo.steps, 1);
+        assert_eq!(*shared.lock().unwrap(), Some(vec![0]));
+
+        // the first step is shared even if the timeout already passed
+        let shared = Mutex::new(Some(vec![0, 1, 2, 3, 4]));
+        algo.run_until_timeout_sharing(Duration::ZERO, &shared);
+        assert_eq!(*shared.lock().unwrap(), Some(vec![0, 1, 2]));
+    }
+}
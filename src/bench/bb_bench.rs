@@ -0,0 +1,70 @@
+use crate::exact::branch_and_bound_matrix::bb_stats::BBStats;
+use crate::exact::branch_and_bound_matrix::{branch_and_bound_matrix_stats, BBConfig};
+use crate::graph::*;
+use csv::Writer;
+use std::io::{self, Write};
+use std::time::Instant;
+
+/// Solves each instance with [`branch_and_bound_matrix_stats`] and writes one CSV line per
+/// instance to `w`. The columns are `graph`, `n`, `m`, `fvs` (size of the solution), `explored`
+/// (number of nodes of the search tree as reported by [`BBStats`]) and `elapsed_sec`.
+pub fn run_benchmark_csv<W: Write>(w: W, instances: &[(&str, AdjListMatrix)]) -> io::Result<()> {
+    let mut writer = Writer::from_writer(w);
+    writer.write_record(["graph", "n", "m", "fvs", "explored", "elapsed_sec"])?;
+
+    for (label, graph) in instances {
+        let mut stats = BBStats::new();
+        let start = Instant::now();
+        let solution = branch_and_bound_matrix_stats(graph, None, &BBConfig::default(), &mut stats)
+            .expect("Branch and bound without upper bound always finds a solution");
+        let elapsed = start.elapsed().as_secs_f64();
+
+        writer.write_record([
+            label.to_string(),
+            graph.len().to_string(),
+            graph.number_of_edges().to_string(),
+            solution.len().to_string(),
+            stats.explored().to_string(),
+            elapsed.to_string(),
+        ])?;
+    }
+
+    writer.flush()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use csv::Reader;
+
+    #[test]
+    fn run_benchmark_csv() {
+        let instances = [
+            ("triangle", AdjListMatrix::from(&[(0, 1), (1, 2), (2, 0)])),
+            (
+                "two_cycles",
+                AdjListMatrix::from(&[(0, 1), (1, 0), (2, 3), (3, 2), (3, 4)]),
+            ),
+        ];
+
+        let mut output = Vec::new();
+        super::run_benchmark_csv(&mut output, &instances).unwrap();
+
+        let mut reader = Reader::from_reader(output.as_slice());
+        assert_eq!(
+            reader.headers().unwrap(),
+            vec!["graph", "n", "m", "fvs", "explored", "elapsed_sec"]
+        );
+
+        let rows: Vec<_> = reader.records().map(|r| r.unwrap()).collect();
+        assert_eq!(rows.len(), 2);
+        for (row, expected) in rows
+            .iter()
+            .zip([["triangle", "3", "3", "1"], ["two_cycles", "5", "5", "2"]])
+        {
+            assert_eq!(row.len(), 6);
+            assert_eq!(row.iter().take(4).collect::<Vec<_>>(), expected);
+            assert!(row[5].parse::<f64>().unwrap() >= 0.0);
+        }
+    }
+}
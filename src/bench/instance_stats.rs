@@ -0,0 +1,106 @@
+use crate::graph::*;
+use log::info;
+use std::fmt::{Display, Formatter};
+
+/// Basic characteristics of an instance, e.g. for the header of a benchmark
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct InstanceStats {
+    pub number_of_nodes: Node,
+    pub number_of_edges: usize,
+    /// Number of SCCs including singletons
+    pub number_of_sccs: usize,
+    pub largest_scc: usize,
+    pub self_loops: usize,
+    /// Number of node pairs {u, v} with u != v connected by both edges (u, v) and (v, u)
+    pub bidirectional_edges: usize,
+    pub acyclic: bool,
+}
+
+impl InstanceStats {
+    /// Writes the stats to the log at info level
+    pub fn log(&self) {
+        info!("{}", self);
+    }
+}
+
+impl Display for InstanceStats {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "n={} m={} sccs={} largest_scc={} self_loops={} bidirectional_edges={} acyclic={}",
+            self.number_of_nodes,
+            self.number_of_edges,
+            self.number_of_sccs,
+            self.largest_scc,
+            self.self_loops,
+            self.bidirectional_edges,
+            self.acyclic
+        )
+    }
+}
+
+/// Computes the [`InstanceStats`] of a graph
+pub fn instance_stats<G: AdjacencyList + AdjacencyTest>(graph: &G) -> InstanceStats {
+    let sccs = graph.strongly_connected_components();
+    let self_loops = graph.vertices().filter(|&u| graph.has_edge(u, u)).count();
+
+    InstanceStats {
+        number_of_nodes: graph.number_of_nodes(),
+        number_of_edges: graph.number_of_edges(),
+        number_of_sccs: sccs.len(),
+        largest_scc: sccs.iter().map(|scc| scc.len()).max().unwrap_or(0),
+        self_loops,
+        bidirectional_edges: graph.bidirectional_edges().len(),
+        // every cycle either is a self-loop or lies within a non-singleton SCC
+        acyclic: self_loops == 0 && sccs.len() == graph.len(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scc_example() {
+        let graph = AdjListMatrix::from(&[
+            (0, 1),
+            (1, 2),
+            (1, 4),
+            (1, 5),
+            (2, 6),
+            (2, 3),
+            (3, 2),
+            (3, 7),
+            (4, 0),
+            (4, 5),
+            (5, 6),
+            (6, 5),
+            (7, 3),
+            (7, 6),
+        ]);
+
+        let stats = instance_stats(&graph);
+        assert_eq!(stats.number_of_nodes, 8);
+        assert_eq!(stats.number_of_edges, 14);
+        assert_eq!(stats.number_of_sccs, 3);
+        assert_eq!(stats.largest_scc, 3);
+        assert_eq!(stats.self_loops, 0);
+        assert_eq!(stats.bidirectional_edges, 3);
+        assert!(!stats.acyclic);
+        stats.log();
+    }
+
+    #[test]
+    fn acyclic_with_loop() {
+        let graph = AdjListMatrix::from(&[(0, 1), (1, 2), (0, 2)]);
+        let stats = instance_stats(&graph);
+        assert_eq!(stats.number_of_sccs, 3);
+        assert_eq!(stats.largest_scc, 1);
+        assert!(stats.acyclic);
+
+        let graph = AdjListMatrix::from(&[(0, 1), (1, 1)]);
+        let stats = instance_stats(&graph);
+        assert_eq!(stats.self_loops, 1);
+        assert!(!stats.acyclic);
+    }
+}
@@ -1,2 +1,4 @@
+pub mod bb_bench;
 pub mod fvs_bench;
+pub mod instance_stats;
 pub mod io;
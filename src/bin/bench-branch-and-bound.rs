@@ -4,7 +4,7 @@ use dfvs::bench::fvs_bench::{FvsBench, InnerIterations, Iterations};
 use dfvs::bench::io::bench_dir;
 use dfvs::exact::branch_and_bound_matrix::bb_stats::BBStats;
 use dfvs::exact::branch_and_bound_matrix::{
-    branch_and_bound_matrix, branch_and_bound_matrix_stats,
+    branch_and_bound_matrix, branch_and_bound_matrix_stats, BBConfig,
 };
 use dfvs::graph::io::FileFormat;
 use dfvs::graph::matrix::AdjMatrixIn;
@@ -112,7 +112,13 @@ fn main() -> std::io::Result<()> {
             |graph, buffer, iteration, num_iterations| {
                 if iteration == num_iterations - 1 {
                     let mut stats = BBStats::new();
-                    let solution = branch_and_bound_matrix_stats(&graph, None, &mut stats).unwrap();
+                    let solution = branch_and_bound_matrix_stats(
+                        &graph,
+                        None,
+                        &BBConfig::default(),
+                        &mut stats,
+                    )
+                    .unwrap();
 
                     stats.write_to_buffer(buffer);
 
@@ -1,10 +1,13 @@
 use bitvec::prelude::*;
 use core::{fmt, iter, mem};
+use itertools::{EitherOrBoth, Itertools};
 use num::{NumCast, ToPrimitive, Unsigned};
+use rayon::prelude::*;
 use std::cmp::Ordering;
 use std::fmt::{Debug, Display, Formatter};
 use std::hash::{Hash, Hasher};
-use std::ops::{AddAssign, Div, Index};
+use std::iter::FromIterator;
+use std::ops::{AddAssign, BitAnd, BitOr, BitXor, Div, Index};
 
 #[derive(Default)]
 pub struct BitSet {
@@ -52,6 +55,36 @@ impl Debug for BitSet {
     }
 }
 
+/// Number of elements [`Display`] prints for a [`BitSet`] before abbreviating the rest
+const DISPLAY_LIMIT: usize = 32;
+
+/// Formats the set bits of a [`BitSet`] in set notation; obtained via [`BitSet::display_with_limit`]
+pub struct BitSetDisplay<'a> {
+    set: &'a BitSet,
+    limit: usize,
+}
+
+impl Display for BitSetDisplay<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{{{}", self.set.iter().take(self.limit).join(", "))?;
+        if self.set.cardinality() > self.limit {
+            if self.limit > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "\u{2026}({} total)", self.set.cardinality())?;
+        }
+        write!(f, "}}")
+    }
+}
+
+/// Prints the set bits as e.g. `{0, 2, 5}`, abbreviating after 32 elements; see
+/// [`BitSet::display_with_limit`]
+impl Display for BitSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.display_with_limit(DISPLAY_LIMIT).fmt(f)
+    }
+}
+
 impl PartialEq for BitSet {
     fn eq(&self, other: &Self) -> bool {
         self.cardinality == other.cardinality && self.bit_vec == other.bit_vec
@@ -92,6 +125,15 @@ impl BitSet {
         }
     }
 
+    /// Creates an empty set of length 0 with storage for at least `bits` bits, so that growing it
+    /// up to this length (e.g. via [`Extend`] or [`BitSet::resize`]) does not reallocate
+    pub fn with_capacity(bits: usize) -> Self {
+        Self {
+            cardinality: 0,
+            bit_vec: BitVec::with_capacity(bits),
+        }
+    }
+
     pub fn from_bitvec(bit_vec: BitVec) -> Self {
         let cardinality = bit_vec.iter().filter(|b| **b).count();
         Self {
@@ -156,16 +198,47 @@ impl BitSet {
         bs
     }
 
+    /// Returns *true* if no index is set in both sets. Bits beyond the length of the shorter
+    /// set are treated as unset.
     #[inline]
     pub fn is_disjoint_with(&self, other: &BitSet) -> bool {
         !self
-            .bit_vec
-            .as_raw_slice()
-            .iter()
-            .zip(other.as_slice().iter())
-            .any(|(x, y)| *x ^ *y != *x | *y)
+            .masked_words()
+            .zip(other.masked_words())
+            .any(|(x, y)| x & y != 0)
+    }
+
+    /// Returns the number of indices set in both sets without allocating a new set. Bits beyond
+    /// the length of the shorter set are treated as unset.
+    pub fn intersection_cardinality(&self, other: &BitSet) -> usize {
+        self.masked_words()
+            .zip(other.masked_words())
+            .map(|(x, y)| (x & y).count_ones() as usize)
+            .sum()
     }
 
+    /// Returns the number of indices set in self but not in `other` without allocating a new
+    /// set. Bits beyond the length of `other` are treated as unset.
+    pub fn difference_cardinality(&self, other: &BitSet) -> usize {
+        let mut other_words = other.masked_words();
+        self.masked_words()
+            .map(|x| (x & !other_words.next().unwrap_or(0)).count_ones() as usize)
+            .sum()
+    }
+
+    /// Returns the number of indices set in exactly one of the sets without allocating a new set.
+    /// The shorter set is treated as if it were extended by unset bits.
+    pub fn hamming_distance(&self, other: &BitSet) -> usize {
+        self.masked_words()
+            .zip_longest(other.masked_words())
+            .map(|pair| match pair {
+                EitherOrBoth::Both(x, y) => (x ^ y).count_ones() as usize,
+                EitherOrBoth::Left(x) | EitherOrBoth::Right(x) => x.count_ones() as usize,
+            })
+            .sum()
+    }
+
+    /// Returns *true* if some index is set in both sets; see [`BitSet::is_disjoint_with`].
     #[inline]
     pub fn intersects_with(&self, other: &BitSet) -> bool {
         !self.is_disjoint_with(other)
@@ -187,6 +260,20 @@ impl BitSet {
         self.bit_vec.as_raw_slice()
     }
 
+    /// Passes the raw words to `f` for bulk modifications and restores the invariants afterwards,
+    /// i.e. clears the bits beyond [`BitSet::len`] and recomputes the cardinality
+    pub fn with_raw_words_mut<F: FnOnce(&mut [usize])>(&mut self, f: F) {
+        f(self.bit_vec.as_raw_mut_slice());
+
+        let tail = self.bit_vec.len() % block_size();
+        if tail != 0 {
+            if let Some(last) = self.bit_vec.as_raw_mut_slice().last_mut() {
+                *last &= (1 << tail) - 1;
+            }
+        }
+        self.cardinality = self.bit_vec.count_ones();
+    }
+
     #[inline]
     pub fn as_bitslice(&self) -> &BitSlice {
         self.bit_vec.as_bitslice()
@@ -219,11 +306,30 @@ impl BitSet {
         }
     }
 
+    /// Toggles the bit `idx` and returns its new value
+    #[inline]
+    pub fn flip_bit(&mut self, idx: usize) -> bool {
+        let value = !*self.bit_vec.get(idx).unwrap();
+        self.bit_vec.set(idx, value);
+        if value {
+            self.cardinality += 1;
+        } else {
+            self.cardinality -= 1;
+        }
+        value
+    }
+
     #[inline]
     pub fn cardinality(&self) -> usize {
         self.cardinality
     }
 
+    /// Returns a formatter printing the set bits as e.g. `{0, 2, 5}`. If there are more than
+    /// `limit` set bits, only the first `limit` are printed, followed by `…(N total)`.
+    pub fn display_with_limit(&self, limit: usize) -> BitSetDisplay<'_> {
+        BitSetDisplay { set: self, limit }
+    }
+
     #[inline]
     pub fn len(&self) -> usize {
         self.bit_vec.len()
@@ -234,6 +340,17 @@ impl BitSet {
         self.bit_vec.is_empty()
     }
 
+    /// Returns the number of bits the set can hold without reallocating
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.bit_vec.capacity()
+    }
+
+    /// Releases the storage beyond the words required for the current length
+    pub fn shrink_to_fit(&mut self) {
+        self.bit_vec.shrink_to_fit();
+    }
+
     #[inline]
     pub fn or(&mut self, other: &BitSet) {
         if other.len() > self.bit_vec.len() {
@@ -243,13 +360,29 @@ impl BitSet {
             .bit_vec
             .as_raw_mut_slice()
             .iter_mut()
-            .zip(other.as_slice().iter())
+            .zip(other.masked_words())
         {
             *x |= y;
         }
         self.cardinality = self.bit_vec.count_ones();
     }
 
+    #[inline]
+    pub fn xor(&mut self, other: &BitSet) {
+        if other.len() > self.bit_vec.len() {
+            self.bit_vec.resize(other.len(), false);
+        }
+        for (x, y) in self
+            .bit_vec
+            .as_raw_mut_slice()
+            .iter_mut()
+            .zip(other.masked_words())
+        {
+            *x ^= y;
+        }
+        self.cardinality = self.bit_vec.count_ones();
+    }
+
     #[inline]
     pub fn resize(&mut self, size: usize) {
         let old_size = self.bit_vec.len();
@@ -259,32 +392,88 @@ impl BitSet {
         }
     }
 
+    /// Same as [`BitSet::resize`], but new positions are set iff `value` is true
+    #[inline]
+    pub fn resize_set(&mut self, size: usize, value: bool) {
+        let old_size = self.bit_vec.len();
+        self.bit_vec.resize(size, value);
+        if size < old_size {
+            self.cardinality = self.bit_vec.count_ones();
+        } else if value {
+            self.cardinality += size - old_size;
+        }
+    }
+
+    /// Intersects self with other. The length of self is kept; bits beyond the length of
+    /// other are treated as unset in other and hence cleared.
     #[inline]
     pub fn and(&mut self, other: &BitSet) {
-        for (x, y) in self
-            .bit_vec
-            .as_raw_mut_slice()
-            .iter_mut()
-            .zip(other.as_slice().iter())
-        {
-            *x &= y;
+        let mut other_words = other.masked_words();
+        for x in self.bit_vec.as_raw_mut_slice().iter_mut() {
+            *x &= other_words.next().unwrap_or(0);
         }
         self.cardinality = self.bit_vec.count_ones();
     }
 
+    /// Removes all bits of other from self. The length of self is kept; bits beyond the length
+    /// of other are treated as unset in other and hence kept.
     #[inline]
     pub fn and_not(&mut self, other: &BitSet) {
         for (x, y) in self
             .bit_vec
             .as_raw_mut_slice()
             .iter_mut()
-            .zip(other.as_slice().iter())
+            .zip(other.masked_words())
         {
             *x &= !y;
         }
         self.cardinality = self.bit_vec.count_ones();
     }
 
+    /// Like [`BitSet::and_not`], but returns true iff at least one bit was removed from self
+    #[inline]
+    pub fn and_not_changed(&mut self, other: &BitSet) -> bool {
+        let before = self.cardinality;
+        self.and_not(other);
+        self.cardinality != before
+    }
+
+    /// Like [`BitSet::and`], but returns true iff at least one bit was removed from self
+    #[inline]
+    pub fn and_changed(&mut self, other: &BitSet) -> bool {
+        let before = self.cardinality;
+        self.and(other);
+        self.cardinality != before
+    }
+
+    /// Like [`BitSet::or`], but returns true iff at least one bit was added to self
+    #[inline]
+    pub fn or_changed(&mut self, other: &BitSet) -> bool {
+        let before = self.cardinality;
+        self.or(other);
+        self.cardinality != before
+    }
+
+    /// Returns the raw words where the bits of the storage beyond `len` are cleared
+    #[inline]
+    fn masked_words(&self) -> impl Iterator<Item = usize> + '_ {
+        let raw = self.bit_vec.as_raw_slice();
+        raw.iter()
+            .enumerate()
+            .map(move |(i, &x)| self.mask_word(i, x))
+    }
+
+    /// Clears the bits of the `i`-th raw word `x` that lie beyond the length of the set
+    #[inline]
+    fn mask_word(&self, i: usize, x: usize) -> usize {
+        let tail = self.bit_vec.len() % block_size();
+        if tail != 0 && i + 1 == self.bit_vec.as_raw_slice().len() {
+            x & ((1 << tail) - 1)
+        } else {
+            x
+        }
+    }
+
     #[inline]
     pub fn not(&mut self) {
         self.bit_vec
@@ -352,6 +541,60 @@ impl BitSet {
         }
     }
 
+    #[inline]
+    pub fn get_last_set(&self) -> Option<usize> {
+        if self.cardinality != 0 {
+            return self.get_prev_set(self.len() - 1);
+        }
+        None
+    }
+
+    /// Returns the smallest set index; same as [`BitSet::get_first_set`]
+    #[inline]
+    pub fn min_set(&self) -> Option<usize> {
+        self.get_first_set()
+    }
+
+    /// Returns the largest set index, scanning the words from the top; same as
+    /// [`BitSet::get_last_set`]
+    #[inline]
+    pub fn max_set(&self) -> Option<usize> {
+        self.get_last_set()
+    }
+
+    /// Writes the smallest set indices in increasing order into `buf` without allocating and
+    /// returns how many were written, i.e. the minimum of `buf.len()` and the cardinality
+    #[inline]
+    pub fn first_n_set(&self, buf: &mut [usize]) -> usize {
+        let mut written = 0;
+        for (slot, idx) in buf.iter_mut().zip(self.iter()) {
+            *slot = idx;
+            written += 1;
+        }
+        written
+    }
+
+    /// Returns the largest index `<= idx` whose bit is set
+    #[inline]
+    pub fn get_prev_set(&self, idx: usize) -> Option<usize> {
+        if self.bit_vec.is_empty() {
+            return None;
+        }
+        let idx = idx.min(self.bit_vec.len() - 1);
+        let mut block_idx = idx / block_size();
+        let word_idx = idx % block_size();
+        let mut block = self.bit_vec.as_raw_slice()[block_idx];
+        block &= usize::MAX >> (block_size() - 1 - word_idx);
+        while block == 0usize {
+            if block_idx == 0 {
+                return None;
+            }
+            block_idx -= 1;
+            block = self.bit_vec.as_raw_slice()[block_idx];
+        }
+        Some(block_idx * block_size() + block_size() - 1 - block.leading_zeros() as usize)
+    }
+
     #[inline]
     pub fn get_first_unset(&self) -> Option<usize> {
         if self.cardinality != self.len() {
@@ -385,6 +628,126 @@ impl BitSet {
         }
     }
 
+    /// Returns the index of the k-th (0-based) set bit or `None` if `k >= self.cardinality()`.
+    #[inline]
+    pub fn select(&self, k: usize) -> Option<usize> {
+        if k >= self.cardinality {
+            return None;
+        }
+        let mut remaining = k;
+        for (block_idx, &block) in self.bit_vec.as_raw_slice().iter().enumerate() {
+            let ones = block.count_ones() as usize;
+            if remaining >= ones {
+                remaining -= ones;
+                continue;
+            }
+            let mut block = block;
+            for _ in 0..remaining {
+                block &= block - 1; // clear lowest set bit
+            }
+            return Some(block_idx * block_size() + block.trailing_zeros() as usize);
+        }
+        None
+    }
+
+    /// Returns the number of set bits strictly below `idx`. This is the inverse of
+    /// [`BitSet::select`], i.e. `bs.rank(bs.select(k).unwrap()) == k`.
+    #[inline]
+    pub fn rank(&self, idx: usize) -> usize {
+        if idx >= self.bit_vec.len() {
+            return self.cardinality;
+        }
+        let raw = self.bit_vec.as_raw_slice();
+        let block_idx = idx / block_size();
+        let word_idx = idx % block_size();
+        let full: usize = raw[..block_idx]
+            .iter()
+            .map(|b| b.count_ones() as usize)
+            .sum();
+        let partial = raw[block_idx] & ((1usize << word_idx) - 1);
+        full + partial.count_ones() as usize
+    }
+
+    /// Returns the number of set bits with index in `[lo, hi)`; `hi` is clamped to `self.len()`
+    #[inline]
+    pub fn count_ones_in_range(&self, lo: usize, hi: usize) -> usize {
+        let hi = hi.min(self.bit_vec.len());
+        if lo >= hi {
+            return 0;
+        }
+        let raw = self.bit_vec.as_raw_slice();
+        let lo_block = lo / block_size();
+        let hi_block = (hi - 1) / block_size();
+        let lo_mask = usize::MAX << (lo % block_size());
+        let hi_mask = usize::MAX >> (block_size() - 1 - (hi - 1) % block_size());
+
+        if lo_block == hi_block {
+            return (raw[lo_block] & lo_mask & hi_mask).count_ones() as usize;
+        }
+
+        let interior: usize = raw[lo_block + 1..hi_block]
+            .iter()
+            .map(|b| b.count_ones() as usize)
+            .sum();
+        interior
+            + (raw[lo_block] & lo_mask).count_ones() as usize
+            + (raw[hi_block] & hi_mask).count_ones() as usize
+    }
+
+    /// Sets all bits with index in `[lo, hi)`.
+    /// ** Panics if `hi > self.len()` **
+    #[inline]
+    pub fn set_range(&mut self, lo: usize, hi: usize) {
+        self.assign_range(lo, hi, true);
+    }
+
+    /// Unsets all bits with index in `[lo, hi)`.
+    /// ** Panics if `hi > self.len()` **
+    #[inline]
+    pub fn unset_range(&mut self, lo: usize, hi: usize) {
+        self.assign_range(lo, hi, false);
+    }
+
+    /// Assigns `value` to the bits in `[lo, hi)` word by word and adjusts the cardinality by the
+    /// number of bits that actually changed
+    fn assign_range(&mut self, lo: usize, hi: usize, value: bool) {
+        assert!(hi <= self.bit_vec.len());
+        if lo >= hi {
+            return;
+        }
+        let lo_block = lo / block_size();
+        let hi_block = (hi - 1) / block_size();
+        let lo_mask = usize::MAX << (lo % block_size());
+        let hi_mask = usize::MAX >> (block_size() - 1 - (hi - 1) % block_size());
+
+        let raw = self.bit_vec.as_raw_mut_slice();
+        let mut before = 0;
+        let mut assign = |word: &mut usize, mask: usize| {
+            before += (*word & mask).count_ones() as usize;
+            if value {
+                *word |= mask;
+            } else {
+                *word &= !mask;
+            }
+        };
+
+        if lo_block == hi_block {
+            assign(&mut raw[lo_block], lo_mask & hi_mask);
+        } else {
+            assign(&mut raw[lo_block], lo_mask);
+            for word in raw[lo_block + 1..hi_block].iter_mut() {
+                assign(word, usize::MAX);
+            }
+            assign(&mut raw[hi_block], hi_mask);
+        }
+
+        if value {
+            self.cardinality += (hi - lo) - before;
+        } else {
+            self.cardinality -= before;
+        }
+    }
+
     #[inline]
     pub fn to_vec(&self) -> Vec<u32> {
         let mut tmp = Vec::with_capacity(self.cardinality);
@@ -405,50 +768,115 @@ impl BitSet {
         self.bit_vec[idx]
     }
 
+    /// Iterates over the non-zero raw words as pairs of (index of the word's first bit, word).
+    /// Bits beyond the length of the set are cleared. Bit j of the word corresponds to index
+    /// base + j of the set.
+    #[inline]
+    pub fn iter_blocks(&self) -> impl Iterator<Item = (usize, usize)> + '_ {
+        self.masked_words()
+            .enumerate()
+            .filter(|&(_, word)| word != 0)
+            .map(|(i, word)| (i * block_size(), word))
+    }
+
+    /// Parallel counterpart of [`BitSet::iter`]: the raw words are distributed among rayon's
+    /// worker threads, so the indices of set bits are yielded in no particular order.
+    pub fn par_iter(&self) -> impl ParallelIterator<Item = usize> + '_ {
+        self.bit_vec
+            .as_raw_slice()
+            .par_iter()
+            .enumerate()
+            .flat_map_iter(move |(i, &x)| {
+                let base = i * block_size();
+                let mut word = self.mask_word(i, x);
+                iter::from_fn(move || {
+                    if word == 0 {
+                        return None;
+                    }
+                    let bit = word.trailing_zeros() as usize;
+                    word &= word - 1;
+                    Some(base + bit)
+                })
+            })
+    }
+
     #[inline]
     pub fn iter(&self) -> BitSetIterator {
         BitSetIterator {
-            iter: self.bit_vec.as_raw_slice().iter(),
+            iter: self.bit_vec.as_raw_slice().iter().enumerate(),
             block: 0,
             idx: 0,
+            back_block: 0,
+            back_idx: 0,
             size: self.bit_vec.len(),
         }
     }
 }
 
+/// Iterates over the indices of all set bits in increasing order (or decreasing order if used
+/// as a [`DoubleEndedIterator`]). Each raw word is consumed by either the front or the back
+/// cursor; once all words are taken, a cursor continues on the remaining bits of the other one.
 pub struct BitSetIterator<'a> {
-    iter: ::std::slice::Iter<'a, usize>,
+    iter: iter::Enumerate<::std::slice::Iter<'a, usize>>,
     block: usize,
     idx: usize,
+    back_block: usize,
+    back_idx: usize,
     size: usize,
 }
 
+impl<'a> BitSetIterator<'a> {
+    /// Returns the word at block `block_idx` with all bits beyond `size` cleared
+    #[inline]
+    fn masked_block(&self, block_idx: usize, block: usize) -> usize {
+        let base = block_idx * block_size();
+        if base + block_size() > self.size {
+            block & ((1usize << (self.size - base)) - 1)
+        } else {
+            block
+        }
+    }
+}
+
 impl<'a> Iterator for BitSetIterator<'a> {
     type Item = usize;
 
     #[inline]
     fn next(&mut self) -> Option<Self::Item> {
         while self.block == 0 {
-            self.block = if let Some(&i) = self.iter.next() {
-                if i == 0 {
-                    self.idx += block_size();
-                    continue;
-                } else {
-                    self.idx = ((self.idx + block_size() - 1) / block_size()) * block_size();
-                    i
-                }
+            if let Some((block_idx, &block)) = self.iter.next() {
+                self.block = self.masked_block(block_idx, block);
+                self.idx = block_idx * block_size();
+            } else if self.back_block != 0 {
+                self.block = mem::take(&mut self.back_block);
+                self.idx = self.back_idx;
             } else {
                 return None;
             }
         }
         let offset = self.block.trailing_zeros() as usize;
-        self.block >>= offset;
-        self.block >>= 1;
-        self.idx += offset + 1;
-        if self.idx > self.size {
-            return None;
+        self.block &= self.block - 1;
+        Some(self.idx + offset)
+    }
+}
+
+impl<'a> DoubleEndedIterator for BitSetIterator<'a> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        while self.back_block == 0 {
+            if let Some((block_idx, &block)) = self.iter.next_back() {
+                self.back_block = self.masked_block(block_idx, block);
+                self.back_idx = block_idx * block_size();
+            } else if self.block != 0 {
+                self.back_block = mem::take(&mut self.block);
+                self.back_idx = self.idx;
+            } else {
+                return None;
+            }
         }
-        Some(self.idx - 1)
+        let offset = block_size() - 1 - self.back_block.leading_zeros() as usize;
+        self.back_block ^= 1 << offset;
+        Some(self.back_idx + offset)
     }
 }
 
@@ -461,10 +889,144 @@ impl Index<usize> for BitSet {
     }
 }
 
+impl Extend<usize> for BitSet {
+    /// Sets all bits of the iterator; the BitSet grows if an index exceeds its length
+    fn extend<I: IntoIterator<Item = usize>>(&mut self, iter: I) {
+        for idx in iter {
+            if idx >= self.len() {
+                self.resize(idx + 1);
+            }
+            self.set_bit(idx);
+        }
+    }
+}
+
+impl FromIterator<usize> for BitSet {
+    /// Creates a BitSet of length `max_index + 1` containing exactly the indices of the iterator
+    fn from_iter<I: IntoIterator<Item = usize>>(iter: I) -> Self {
+        let mut bs = BitSet::default();
+        bs.extend(iter);
+        bs
+    }
+}
+
+/// Implements a binary operator for (references of) BitSets by forwarding to the in-place
+/// operation. The result has the length of the larger operand.
+macro_rules! impl_bitset_binop {
+    ($trait:ident, $fn:ident, $method:ident) => {
+        impl $trait<&BitSet> for &BitSet {
+            type Output = BitSet;
+
+            fn $fn(self, rhs: &BitSet) -> BitSet {
+                // all supported operators are commutative, so we can always copy the shorter
+                // operand and apply the operation with the longer one
+                let (shorter, longer) = if self.len() < rhs.len() {
+                    (self, rhs)
+                } else {
+                    (rhs, self)
+                };
+                let mut result = shorter.clone();
+                result.resize(longer.len());
+                result.$method(longer);
+                result
+            }
+        }
+
+        impl $trait for BitSet {
+            type Output = BitSet;
+
+            fn $fn(self, rhs: BitSet) -> BitSet {
+                (&self).$fn(&rhs)
+            }
+        }
+    };
+}
+
+impl_bitset_binop!(BitAnd, bitand, and);
+impl_bitset_binop!(BitOr, bitor, or);
+impl_bitset_binop!(BitXor, bitxor, xor);
+
+#[cfg(feature = "serde")]
+mod serde_impl {
+    use super::{block_size, BitSet};
+    use bitvec::prelude::BitVec;
+    use serde::{de::Error, Deserialize, Deserializer, Serialize, Serializer};
+
+    /// A BitSet is stored as its length and the raw words, which keeps dense sets compact
+    #[derive(Serialize, Deserialize)]
+    #[serde(rename = "BitSet")]
+    struct BitSetRepr {
+        len: usize,
+        words: Vec<usize>,
+    }
+
+    impl Serialize for BitSet {
+        fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+            let mut words = self.as_slice().to_vec();
+            // the storage beyond len may contain garbage (e.g. after `not`)
+            if !self.len().is_multiple_of(block_size()) {
+                if let Some(last) = words.last_mut() {
+                    *last &= (1 << (self.len() % block_size())) - 1;
+                }
+            }
+            BitSetRepr {
+                len: self.len(),
+                words,
+            }
+            .serialize(serializer)
+        }
+    }
+
+    impl<'de> Deserialize<'de> for BitSet {
+        fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+            let BitSetRepr { len, words } = BitSetRepr::deserialize(deserializer)?;
+            let expected_words = len.div_ceil(block_size());
+            if words.len() != expected_words {
+                return Err(D::Error::custom(format!(
+                    "BitSet of length {} requires {} words, got {}",
+                    len,
+                    expected_words,
+                    words.len()
+                )));
+            }
+            let mut bit_vec = BitVec::from_vec(words);
+            bit_vec.truncate(len);
+            Ok(BitSet::from_bitvec(bit_vec))
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::bitset::BitSet;
+    use itertools::Itertools;
     use rand::Rng;
+    use rayon::prelude::*;
+
+    #[test]
+    fn display() {
+        assert_eq!(BitSet::new(10).to_string(), "{}");
+        assert_eq!(
+            BitSet::new_all_unset_but(10, [0usize, 2, 5]).to_string(),
+            "{0, 2, 5}"
+        );
+
+        let set = BitSet::new_all_set(100);
+        let expected = format!("{{{}, \u{2026}(100 total)}}", (0..32).join(", "));
+        assert_eq!(set.to_string(), expected);
+        assert_eq!(
+            set.display_with_limit(3).to_string(),
+            "{0, 1, 2, \u{2026}(100 total)}"
+        );
+        assert_eq!(
+            set.display_with_limit(0).to_string(),
+            "{\u{2026}(100 total)}"
+        );
+        assert_eq!(
+            format!("{}", set.display_with_limit(100)),
+            format!("{{{}}}", (0..100).join(", "))
+        );
+    }
 
     #[test]
     fn iter() {
@@ -499,6 +1061,139 @@ mod tests {
         }
     }
 
+    #[test]
+    fn iter_prev() {
+        let mut bs = BitSet::new(256);
+
+        let a: Vec<usize> = (0..256).rev().filter(|i| i % 2 == 0).collect();
+        for i in &a {
+            bs.set_bit(*i);
+        }
+        assert_eq!(bs.get_last_set(), Some(254));
+
+        let mut c = Vec::new();
+        let mut v = bs.get_last_set();
+        while let Some(x) = v {
+            c.push(x);
+            v = if x == 0 { None } else { bs.get_prev_set(x - 1) };
+        }
+        assert_eq!(a, c);
+
+        assert_eq!(bs.get_prev_set(1000), Some(254));
+        assert_eq!(bs.get_prev_set(129), Some(128));
+        assert_eq!(bs.get_prev_set(128), Some(128));
+        assert_eq!(bs.get_prev_set(127), Some(126));
+
+        let mut bs = BitSet::new(257);
+        assert_eq!(bs.get_last_set(), None);
+        assert_eq!(bs.get_prev_set(256), None);
+        bs.set_bit(256);
+        assert_eq!(bs.get_last_set(), Some(256));
+        assert_eq!(bs.get_prev_set(255), None);
+
+        assert_eq!(BitSet::new(0).get_last_set(), None);
+        assert_eq!(BitSet::new(0).get_prev_set(0), None);
+    }
+
+    #[test]
+    fn iter_rev() {
+        let mut rng = rand::thread_rng();
+        for n in [0, 1, 63, 64, 65, 200, 257] {
+            let mut bs = BitSet::new(n);
+            for _ in 0..n / 2 {
+                bs.set_bit(rng.gen_range(0..n));
+            }
+            let mut forward: Vec<usize> = bs.iter().collect();
+            forward.reverse();
+            let backward: Vec<usize> = bs.iter().rev().collect();
+            assert_eq!(forward, backward);
+        }
+
+        // trailing bits of the raw storage beyond len must not be reported
+        let mut bs = BitSet::new(70);
+        bs.not();
+        assert_eq!(bs.iter().next_back(), Some(69));
+        assert_eq!(bs.iter().count(), 70);
+    }
+
+    #[test]
+    fn iter_blocks() {
+        let n = 1000;
+        // leave some words empty
+        let mut bs =
+            BitSet::new_all_unset_but(n, (0..n).filter(|i| (i / 100) % 3 == 0 && i % 5 != 0));
+
+        let mut reconstructed = BitSet::new(n);
+        for (base, word) in bs.iter_blocks() {
+            assert_ne!(word, 0);
+            for j in 0..usize::BITS as usize {
+                if (word >> j) & 1 == 1 {
+                    reconstructed.set_bit(base + j);
+                }
+            }
+        }
+        assert_eq!(reconstructed, bs);
+
+        bs.set_all();
+        bs.resize(n - 3);
+        let ones: u32 = bs.iter_blocks().map(|(_, word)| word.count_ones()).sum();
+        assert_eq!(ones as usize, n - 3);
+        assert_eq!(BitSet::new(100).iter_blocks().count(), 0);
+    }
+
+    #[test]
+    fn par_iter() {
+        let n = 100_000 + 17;
+        let mut bs = BitSet::new_all_unset_but(n, (0..n).filter(|i| i % 7 == 0 || i % 11 == 3));
+        let mut par: Vec<usize> = bs.par_iter().collect();
+        par.sort_unstable();
+        assert_eq!(par, bs.iter().collect::<Vec<_>>());
+
+        // bits beyond the length must not be reported after shrinking
+        bs.set_all();
+        bs.resize(n - 10);
+        assert_eq!(bs.par_iter().count(), n - 10);
+        assert_eq!(bs.par_iter().max(), Some(n - 11));
+        assert_eq!(BitSet::new(0).par_iter().count(), 0);
+    }
+
+    #[test]
+    fn iter_meet_in_the_middle() {
+        let a: Vec<usize> = (0..300).filter(|i| i % 3 != 1).collect();
+        let bs = BitSet::new_all_unset_but(300, a.iter().copied());
+
+        for front in 0..=a.len() {
+            let mut iter = bs.iter();
+            let mut collected: Vec<usize> = Vec::new();
+            for _ in 0..front {
+                collected.push(iter.next().unwrap());
+            }
+            let mut back: Vec<usize> = Vec::new();
+            while let Some(x) = iter.next_back() {
+                back.push(x);
+            }
+            assert_eq!(iter.next(), None);
+            back.reverse();
+            collected.extend(back);
+            assert_eq!(collected, a);
+        }
+
+        // alternate between both ends
+        let mut iter = bs.iter();
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        while let Some(x) = iter.next() {
+            front.push(x);
+            match iter.next_back() {
+                Some(x) => back.push(x),
+                None => break,
+            }
+        }
+        back.reverse();
+        front.extend(back);
+        assert_eq!(front, a);
+    }
+
     #[test]
     fn get_set() {
         let n = 257;
@@ -549,6 +1244,469 @@ mod tests {
         for i in (0..n).filter(|i| i % 2 == 0) {
             assert_eq!(false, tmp[i]);
         }
+
+        let mut tmp = bs1.clone();
+        tmp.xor(&bs2);
+        assert!(tmp.full());
+
+        let mut tmp = bs1.clone();
+        tmp.xor(&bs1);
+        assert!(tmp.empty());
+    }
+
+    #[test]
+    fn logical_ops_changed() {
+        let mut set = BitSet::new_all_unset_but(100, [1u32, 5, 70]);
+        let disjoint = BitSet::new_all_unset_but(100, [0u32, 6, 99]);
+        let overlapping = BitSet::new_all_unset_but(100, [5u32, 6]);
+
+        assert!(!set.and_not_changed(&disjoint));
+        assert_eq!(set.cardinality(), 3);
+        assert!(set.and_not_changed(&overlapping));
+        assert_eq!(set.iter().collect_vec(), vec![1, 70]);
+        assert!(!set.and_not_changed(&overlapping));
+
+        assert!(!set.or_changed(&BitSet::new_all_unset_but(100, [1u32])));
+        assert!(set.or_changed(&overlapping));
+        assert_eq!(set.iter().collect_vec(), vec![1, 5, 6, 70]);
+
+        assert!(!set.and_changed(&BitSet::new_all_set(100)));
+        assert!(set.and_changed(&overlapping));
+        assert_eq!(set.iter().collect_vec(), vec![5, 6]);
+    }
+
+    #[test]
+    fn intersection_difference_cardinality() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let random_set = |rng: &mut rand::rngs::ThreadRng| {
+                let n = rng.gen_range(0..300);
+                let mut bs = BitSet::new(n);
+                for _ in 0..rng.gen_range(0..n + 1) {
+                    bs.set_bit(rng.gen_range(0..n));
+                }
+                bs
+            };
+            let a = random_set(&mut rng);
+            let b = random_set(&mut rng);
+
+            let mut inter = a.clone();
+            inter.and(&b);
+            assert_eq!(a.intersection_cardinality(&b), inter.cardinality());
+            assert_eq!(b.intersection_cardinality(&a), inter.cardinality());
+
+            let mut diff = a.clone();
+            diff.and_not(&b);
+            assert_eq!(a.difference_cardinality(&b), diff.cardinality());
+        }
+    }
+
+    #[test]
+    fn hamming_distance() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..100 {
+            let n = rng.gen_range(1..300);
+            let m = rng.gen_range(1..300);
+            let a = BitSet::new_all_unset_but(n, (0..n).filter(|_| rng.gen_bool(0.3)));
+            let b = BitSet::new_all_unset_but(m, (0..m).filter(|_| rng.gen_bool(0.3)));
+
+            let distance = a.difference_cardinality(&b) + b.difference_cardinality(&a);
+            assert_eq!(a.hamming_distance(&b), distance);
+            assert_eq!(b.hamming_distance(&a), distance);
+            assert_eq!(a.hamming_distance(&b), (&a ^ &b).cardinality());
+            assert_eq!(a.hamming_distance(&a), 0);
+        }
+    }
+
+    #[test]
+    fn logic_mismatched_lengths() {
+        let short_idx = [1usize, 5, 63];
+        let long_idx = [1usize, 63, 64, 150, 199];
+        let short = BitSet::new_all_unset_but(64, short_idx);
+        let long = BitSet::new_all_unset_but(200, long_idx);
+
+        {
+            let mut bs = long.clone();
+            bs.and(&short);
+            assert_eq!(bs.len(), 200);
+            assert_eq!(bs.to_vec(), vec![1, 63]);
+            assert_eq!(bs.cardinality(), 2);
+
+            let mut bs = short.clone();
+            bs.and(&long);
+            assert_eq!(bs.len(), 64);
+            assert_eq!(bs.to_vec(), vec![1, 63]);
+        }
+
+        {
+            let mut bs = long.clone();
+            bs.and_not(&short);
+            assert_eq!(bs.len(), 200);
+            assert_eq!(bs.to_vec(), vec![64, 150, 199]);
+
+            let mut bs = short.clone();
+            bs.and_not(&long);
+            assert_eq!(bs.len(), 64);
+            assert_eq!(bs.to_vec(), vec![5]);
+        }
+
+        {
+            let high = BitSet::new_all_unset_but(200, [64usize, 150]);
+            assert!(high.is_disjoint_with(&short));
+            assert!(short.is_disjoint_with(&high));
+            assert!(!high.intersects_with(&short));
+            assert!(long.intersects_with(&short));
+            assert!(short.intersects_with(&long));
+        }
+
+        // bits of the storage beyond len must not leak into the result
+        {
+            let mut short = BitSet::new(70);
+            short.not();
+            let mut bs = BitSet::new_all_set(200);
+            bs.and(&short);
+            assert_eq!(bs.cardinality(), 70);
+
+            let mut bs = BitSet::new_all_set(200);
+            bs.and_not(&short);
+            assert_eq!(bs.cardinality(), 130);
+
+            assert!(short.is_disjoint_with(&BitSet::new_all_unset_but(200, [100usize])));
+        }
+    }
+
+    #[test]
+    fn xor() {
+        let mut bs1 = BitSet::new_all_unset_but(100, [1usize, 5, 70, 99]);
+        let bs2 = BitSet::new_all_unset_but(200, [5usize, 70, 150]);
+
+        bs1.xor(&bs2);
+        assert_eq!(bs1.len(), 200);
+        assert_eq!(bs1.cardinality(), 3);
+        assert_eq!(bs1.iter().collect::<Vec<_>>(), vec![1, 99, 150]);
+
+        // xor-ing twice restores the original values
+        bs1.xor(&bs2);
+        assert_eq!(bs1.cardinality(), 4);
+        assert_eq!(bs1.iter().collect::<Vec<_>>(), vec![1, 5, 70, 99]);
+
+        // bits of the storage beyond the length of a shorter other must not leak into the result
+        let mut short = BitSet::new(70);
+        short.not();
+        let mut bs = BitSet::new(200);
+        bs.xor(&short);
+        assert_eq!(bs.cardinality(), 70);
+        assert_eq!(bs.iter().collect::<Vec<_>>(), (0..70).collect::<Vec<_>>());
+
+        let mut bs = BitSet::new(200);
+        bs.or(&short);
+        assert_eq!(bs.cardinality(), 70);
+        assert_eq!(bs.get_last_set(), Some(69));
+    }
+
+    #[test]
+    fn operators() {
+        let a = BitSet::new_all_unset_but(100, [1usize, 5, 70, 99]);
+        let b = BitSet::new_all_unset_but(200, [5usize, 70, 150]);
+
+        for (result, expected) in [
+            (&a & &b, vec![5, 70]),
+            (&b & &a, vec![5, 70]),
+            (&a | &b, vec![1, 5, 70, 99, 150]),
+            (&b | &a, vec![1, 5, 70, 99, 150]),
+            (&a ^ &b, vec![1, 99, 150]),
+            (&b ^ &a, vec![1, 99, 150]),
+            (a.clone() & b.clone(), vec![5, 70]),
+            (a.clone() | b.clone(), vec![1, 5, 70, 99, 150]),
+            (a.clone() ^ b.clone(), vec![1, 99, 150]),
+        ] {
+            assert_eq!(result.len(), 200);
+            assert_eq!(result.cardinality(), expected.len());
+            assert_eq!(result.iter().collect::<Vec<_>>(), expected);
+        }
+    }
+
+    #[test]
+    fn select_rank() {
+        let mut rng = rand::thread_rng();
+        for _ in 0..20 {
+            let n = 257;
+            let mut bs = BitSet::new(n);
+            for _ in 0..rng.gen_range(0..n) {
+                bs.set_bit(rng.gen_range(0..n));
+            }
+            let ones: Vec<_> = bs.iter().collect();
+
+            for (k, &idx) in ones.iter().enumerate() {
+                assert_eq!(bs.select(k), Some(idx));
+                assert_eq!(bs.rank(idx), k);
+            }
+            assert_eq!(bs.select(ones.len()), None);
+
+            for idx in 0..=n {
+                assert_eq!(bs.rank(idx), ones.iter().filter(|&&x| x < idx).count());
+            }
+        }
+
+        let bs = BitSet::new_all_set(257);
+        assert_eq!(bs.select(0), Some(0));
+        assert_eq!(bs.select(64), Some(64));
+        assert_eq!(bs.select(256), Some(256));
+        assert_eq!(bs.select(257), None);
+        assert_eq!(bs.rank(64), 64);
+        assert_eq!(bs.rank(257), 257);
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn serde_round_trip() {
+        use serde_test::{assert_de_tokens_error, assert_tokens, Token};
+
+        fn tokens(len: usize, words: &[usize]) -> Vec<Token> {
+            let mut tokens = vec![
+                Token::Struct {
+                    name: "BitSet",
+                    len: 2,
+                },
+                Token::Str("len"),
+                Token::U64(len as u64),
+                Token::Str("words"),
+                Token::Seq {
+                    len: Some(words.len()),
+                },
+            ];
+            tokens.extend(words.iter().map(|&w| Token::U64(w as u64)));
+            tokens.extend([Token::SeqEnd, Token::StructEnd]);
+            tokens
+        }
+
+        for n in [0usize, 1, 200] {
+            let words = n.div_ceil(64);
+
+            let empty = BitSet::new(n);
+            assert_tokens(&empty, &tokens(n, &vec![0; words]));
+
+            let full = BitSet::new_all_set(n);
+            let mut full_words = vec![usize::MAX; words];
+            if n % 64 != 0 {
+                full_words[words - 1] = (1 << (n % 64)) - 1;
+            }
+            assert_tokens(&full, &tokens(n, &full_words));
+            assert_eq!(full.cardinality(), n);
+        }
+
+        let sparse = BitSet::new_all_unset_but(200, [3usize, 64, 199]);
+        assert_tokens(&sparse, &tokens(200, &[1 << 3, 1, 0, 1 << 7]));
+
+        assert_de_tokens_error::<BitSet>(
+            &tokens(200, &[1]),
+            "BitSet of length 200 requires 4 words, got 1",
+        );
+    }
+
+    #[test]
+    fn with_raw_words_mut() {
+        let bits = usize::BITS as usize;
+        let mut set = BitSet::new_all_unset_but(2 * bits + 5, [3usize]);
+        set.with_raw_words_mut(|words| {
+            assert_eq!(words.len(), 3);
+            words[1] = usize::MAX;
+            words[2] = usize::MAX; // only 5 of these bits belong to the set
+        });
+        assert_eq!(set.cardinality(), 1 + bits + 5);
+        assert!(set[3] && !set[4] && !set[bits - 1]);
+        assert!((bits..2 * bits + 5).all(|i| set[i]));
+        assert_eq!(set.as_slice()[2], (1 << 5) - 1);
+        assert_eq!(set.iter().count(), set.cardinality());
+
+        set.with_raw_words_mut(|words| words[1] = 0b101);
+        assert_eq!(set.cardinality(), 1 + 2 + 5);
+        assert_eq!(set.iter().take(3).collect::<Vec<_>>(), [3, bits, bits + 2]);
+    }
+
+    #[test]
+    fn min_max_set() {
+        assert_eq!(BitSet::new(0).min_set(), None);
+        assert_eq!(BitSet::new(1000).max_set(), None);
+
+        let mut set = BitSet::new_all_unset_but(1000, [77usize, 130, 513, 900]);
+        assert_eq!(set.min_set(), Some(77));
+        assert_eq!(set.max_set(), Some(900));
+
+        set.unset_bit(900);
+        set.set_bit(999);
+        set.set_bit(0);
+        assert_eq!(set.min_set(), Some(0));
+        assert_eq!(set.max_set(), Some(999));
+
+        let set = BitSet::new_all_unset_but(1000, [64usize]);
+        assert_eq!((set.min_set(), set.max_set()), (Some(64), Some(64)));
+    }
+
+    #[test]
+    fn first_n_set() {
+        let set = BitSet::new_all_unset_but(300, [3usize, 64, 65, 200, 299]);
+
+        let mut buf = [usize::MAX; 3];
+        assert_eq!(set.first_n_set(&mut buf), 3);
+        assert_eq!(buf, [3, 64, 65]);
+
+        let mut buf = [usize::MAX; 8];
+        assert_eq!(set.first_n_set(&mut buf), 5);
+        assert_eq!(buf[..5], [3, 64, 65, 200, 299]);
+        assert!(buf[5..].iter().all(|&x| x == usize::MAX));
+
+        assert_eq!(set.first_n_set(&mut []), 0);
+        assert_eq!(BitSet::new(100).first_n_set(&mut buf), 0);
+    }
+
+    #[test]
+    fn resize_set() {
+        let mut set = BitSet::new(0);
+        set.resize_set(200, true);
+        assert_eq!(set.len(), 200);
+        assert_eq!(set.cardinality(), 200);
+        assert!(set.full());
+
+        set.unset_bit(10);
+        set.resize_set(300, false);
+        assert_eq!(set.cardinality(), 199);
+        set.resize_set(330, true);
+        assert_eq!(set.cardinality(), 229);
+        assert_eq!(
+            set.iter().filter(|&i| i >= 200).collect_vec(),
+            (300..330).collect_vec()
+        );
+
+        set.resize_set(5, true);
+        assert_eq!(set.cardinality(), 5);
+        set.resize_set(0, true);
+        assert!(set.empty());
+    }
+
+    #[test]
+    fn with_capacity() {
+        let mut bs = BitSet::with_capacity(300);
+        assert_eq!(bs.len(), 0);
+        assert!(bs.capacity() >= 300);
+
+        bs.extend([0]);
+        let storage = bs.as_slice().as_ptr();
+        bs.extend((3..300).step_by(3));
+        assert_eq!(bs.len(), 298);
+        assert_eq!(bs.cardinality(), 100);
+        assert_eq!(bs.as_slice().as_ptr(), storage);
+
+        bs.resize(300);
+        assert_eq!(bs.as_slice().as_ptr(), storage);
+
+        bs.resize(10);
+        bs.shrink_to_fit();
+        assert!(bs.capacity() < 300);
+        assert_eq!(bs.iter().collect::<Vec<_>>(), vec![0, 3, 6, 9]);
+    }
+
+    #[test]
+    fn flip_bit() {
+        let mut bs = BitSet::new_all_unset_but(100, [3usize, 70]);
+        let original = bs.clone();
+
+        assert!(bs.flip_bit(5));
+        assert!(bs[5]);
+        assert_eq!(bs.cardinality(), 3);
+        assert!(!bs.flip_bit(5));
+        assert_eq!(bs, original);
+
+        assert!(!bs.flip_bit(70));
+        assert!(!bs[70]);
+        assert_eq!(bs.cardinality(), 1);
+        assert!(bs.flip_bit(70));
+        assert_eq!(bs, original);
+        assert_eq!(bs.cardinality(), 2);
+    }
+
+    #[test]
+    fn set_unset_range() {
+        let mut rng = rand::thread_rng();
+        for n in [1, 63, 64, 65, 200, 257] {
+            let mut bs = BitSet::new_all_unset_but(n, (0..n).filter(|_| rng.gen_bool(0.5)));
+            let mut expected = bs.clone();
+
+            for _ in 0..200 {
+                let lo = rng.gen_range(0..=n);
+                let hi = rng.gen_range(lo..=n);
+                if rng.gen_bool(0.5) {
+                    bs.set_range(lo, hi);
+                    (lo..hi).for_each(|i| {
+                        expected.set_bit(i);
+                    });
+                } else {
+                    bs.unset_range(lo, hi);
+                    (lo..hi).for_each(|i| {
+                        expected.unset_bit(i);
+                    });
+                }
+                assert_eq!(bs, expected);
+                assert_eq!(bs.cardinality(), bs.iter().count());
+            }
+
+            bs.set_range(0, n);
+            assert!(bs.full());
+            bs.unset_range(n / 2, n);
+            assert_eq!(bs.cardinality(), n / 2);
+            bs.unset_range(0, 0);
+            assert_eq!(bs.cardinality(), n / 2);
+        }
+    }
+
+    #[test]
+    fn count_ones_in_range() {
+        let mut rng = rand::thread_rng();
+        for n in [1, 64, 200, 257] {
+            let mut bs = BitSet::new(n);
+            for _ in 0..n / 2 {
+                bs.set_bit(rng.gen_range(0..n));
+            }
+
+            for _ in 0..200 {
+                let lo = rng.gen_range(0..n + 10);
+                let hi = rng.gen_range(0..n + 10);
+                let naive = bs.iter().filter(|&i| lo <= i && i < hi).count();
+                assert_eq!(bs.count_ones_in_range(lo, hi), naive);
+            }
+
+            assert_eq!(bs.count_ones_in_range(0, n), bs.cardinality());
+            assert_eq!(bs.count_ones_in_range(0, usize::MAX), bs.cardinality());
+            assert_eq!(bs.count_ones_in_range(n / 2, n / 2), 0);
+        }
+
+        let mut bs = BitSet::new(70);
+        bs.not();
+        assert_eq!(bs.count_ones_in_range(3, 10), 7);
+        assert_eq!(bs.count_ones_in_range(60, 1000), 10);
+    }
+
+    #[test]
+    fn from_iter_extend() {
+        let bs: BitSet = [3usize, 70, 3, 1].iter().copied().collect();
+        assert_eq!(bs.len(), 71);
+        assert_eq!(bs.cardinality(), 3);
+        assert_eq!(bs.to_vec(), vec![1, 3, 70]);
+
+        let mut bs = bs;
+        bs.extend([70usize, 0, 200, 200]);
+        assert_eq!(bs.len(), 201);
+        assert_eq!(bs.cardinality(), 5);
+        assert_eq!(bs.to_vec(), vec![0, 1, 3, 70, 200]);
+
+        let mut bs = BitSet::new(100);
+        bs.extend([5usize, 10]);
+        assert_eq!(bs.len(), 100);
+        assert_eq!(bs.cardinality(), 2);
+
+        let bs: BitSet = std::iter::empty().collect();
+        assert_eq!(bs.len(), 0);
+        assert_eq!(bs.cardinality(), 0);
     }
 
     #[test]
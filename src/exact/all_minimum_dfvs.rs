@@ -0,0 +1,135 @@
+use crate::bitset::BitSet;
+use crate::exact::branch_and_bound_matrix::branch_and_bound_matrix;
+use crate::graph::*;
+
+/// Returns all minimum DFVS (each sorted, in lexicographic order), but at most `max_solutions`
+/// many, since their number can grow exponentially.
+///
+/// The size `k` of a minimum DFVS is computed by [`branch_and_bound_matrix`]. Then we branch on
+/// the nodes of some cycle: either its first node is in the solution, or the first one is not but
+/// the second one is, and so on. Nodes excluded this way are never deleted in the respective
+/// subtree, so every DFVS with at most `k` nodes is reported exactly once.
+///
+/// # Example
+/// ```
+/// use dfvs::exact::all_minimum_dfvs::all_minimum_dfvs;
+/// use dfvs::graph::*;
+/// let graph = AdjArray::from(&[(0, 1), (1, 0), (1, 2), (2, 1)]);
+/// assert_eq!(all_minimum_dfvs(&graph, 10), vec![vec![1]]);
+/// ```
+pub fn all_minimum_dfvs<G: AdjacencyList>(graph: &G, max_solutions: usize) -> Vec<Vec<Node>> {
+    let optimum = branch_and_bound_matrix(graph, None).unwrap();
+
+    let mut working_copy = AdjArrayIn::new(graph.len());
+    for (u, v) in graph.edges_iter() {
+        working_copy.add_edge(u, v);
+    }
+
+    let mut enumerator = Enumerator {
+        excluded: BitSet::new(graph.len()),
+        chosen: Vec::with_capacity(optimum.len()),
+        solutions: Vec::new(),
+        max_solutions,
+    };
+    enumerator.enumerate(&working_copy, optimum.len());
+
+    enumerator.solutions.sort_unstable();
+    enumerator.solutions
+}
+
+struct Enumerator {
+    /// Nodes that must not be deleted in the current subtree
+    excluded: BitSet,
+    chosen: Vec<Node>,
+    solutions: Vec<Vec<Node>>,
+    max_solutions: usize,
+}
+
+impl Enumerator {
+    fn enumerate(&mut self, graph: &AdjArrayIn, budget: usize) {
+        if self.solutions.len() >= self.max_solutions {
+            return;
+        }
+
+        let cycle = match graph.find_cycle() {
+            None => {
+                let mut solution = self.chosen.clone();
+                solution.sort_unstable();
+                self.solutions.push(solution);
+                return;
+            }
+            Some(cycle) => cycle,
+        };
+
+        if budget == 0 {
+            return;
+        }
+
+        let candidates: Vec<Node> = cycle
+            .into_iter()
+            .filter(|&u| !self.excluded[u as usize])
+            .collect();
+
+        for &u in &candidates {
+            let mut subgraph = graph.clone();
+            subgraph.remove_edges_at_node(u);
+
+            self.chosen.push(u);
+            self.enumerate(&subgraph, budget - 1);
+            self.chosen.pop();
+
+            self.excluded.set_bit(u as usize);
+        }
+
+        for &u in &candidates {
+            self.excluded.unset_bit(u as usize);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random_models::gnp::generate_gnp;
+    use crate::utils::*;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
+
+    #[test]
+    fn ties() {
+        // 1, 2, 3, 4 form a cycle, 0 and 5 carry loops
+        let graph = AdjArray::from(&[(0, 0), (1, 2), (2, 3), (3, 4), (4, 1), (5, 5)]);
+        assert_eq!(
+            all_minimum_dfvs(&graph, 100),
+            vec![vec![0, 1, 5], vec![0, 2, 5], vec![0, 3, 5], vec![0, 4, 5]]
+        );
+        assert_eq!(all_minimum_dfvs(&graph, 2).len(), 2);
+
+        assert_eq!(
+            all_minimum_dfvs(&AdjArray::from(&[(0, 1)]), 10),
+            vec![Vec::<Node>::new()]
+        );
+    }
+
+    #[test]
+    fn matches_exhaustive_enumeration() {
+        let mut rng = Pcg64Mcg::seed_from_u64(4);
+        for _ in 0..20 {
+            let graph: AdjArrayIn = generate_gnp(&mut rng, 10, 0.2);
+            let k = branch_and_bound_matrix(&graph, None).unwrap().len() as u32;
+
+            let mut expected: Vec<Vec<Node>> =
+                AllIntSubsets::start_with_bits_set(k, graph.len() as u32)
+                    .take_while(|x| x.count_ones() == k)
+                    .map(|x| x.iter_ones().map(|u| u as Node).collect::<Vec<_>>())
+                    .filter(|sol| {
+                        let mask = BitSet::new_all_set_but(graph.len(), sol.iter().copied());
+                        graph.vertex_induced(&mask).0.is_acyclic()
+                    })
+                    .collect();
+
+            expected.sort_unstable();
+            assert_eq!(all_minimum_dfvs(&graph, usize::MAX), expected);
+        }
+    }
+}
@@ -0,0 +1,56 @@
+use super::bb_core::BranchStrategy;
+use std::sync::atomic::AtomicBool;
+use std::time::Instant;
+
+/// Settings of the matrix branch and bound passed to its `_stats` entry points (e.g.
+/// [`super::branch_and_bound_matrix_stats`]). The config is never modified by the search, so
+/// resetting or merging the collected [`super::bb_stats::BBStats`] cannot change the search.
+#[derive(Clone, Copy, Debug)]
+pub struct BBConfig<'a> {
+    /// Selects the node the branch and bound branches on
+    pub branch_strategy: BranchStrategy,
+    /// The adjacency rows are stored with at least this many bits (rounded up to a power of two),
+    /// even if a narrower representation fits the graph, e.g. to benchmark the different graph
+    /// representations in isolation. Must not exceed 128.
+    pub min_width: usize,
+    /// The search gives up once the deadline passed
+    pub deadline: Option<Instant>,
+    /// The search gives up (at the next SCC boundary) once the flag is set, e.g. by another
+    /// thread or a signal handler
+    pub cancel: Option<&'a AtomicBool>,
+    /// If non-zero, the results of up to this many subproblems (keyed by their adjacency matrix)
+    /// are cached, which pays off if the same subgraphs recur across branches
+    pub memo_entries: usize,
+}
+
+impl<'a> Default for BBConfig<'a> {
+    fn default() -> Self {
+        Self {
+            branch_strategy: BranchStrategy::default(),
+            min_width: 8,
+            deadline: None,
+            cancel: None,
+            memo_entries: 0,
+        }
+    }
+}
+
+impl<'a> BBConfig<'a> {
+    /// Default config that makes the search give up once `deadline` passed
+    pub fn with_deadline(deadline: Instant) -> Self {
+        Self {
+            deadline: Some(deadline),
+            ..Self::default()
+        }
+    }
+
+    /// Number of bits per adjacency row used for a graph with `n` nodes
+    /// ** Panics if `min_width` exceeds 128 **
+    pub(super) fn row_width(&self, n: usize) -> usize {
+        assert!(
+            self.min_width <= 128,
+            "The matrix branch and bound supports at most 128 bits per row"
+        );
+        n.max(self.min_width).max(8).next_power_of_two()
+    }
+}
@@ -1,15 +1,46 @@
+use super::bb_search::{BBSearch, MemoKey};
 use super::*;
+use crate::log::{log_if, Level};
 use arrayvec::ArrayVec;
-use num::{One, Zero};
+use num::cast::AsPrimitive;
+use num::{FromPrimitive, One, Zero};
 use std::any::Any;
 
+/// Determines which node the branch and bound branches on (i.e. deletes in one branch and
+/// contracts in the other). Set via [`BBConfig::branch_strategy`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum BranchStrategy {
+    /// A node with most 2-cycles; if there are none, a node with maximum out-degree
+    #[default]
+    MostUndirectedEdges,
+    /// A node with maximum total (in- plus out-) degree
+    HighestDegree,
+    /// Always the first node of the (sub)graph
+    LowestIndex,
+}
+
+impl BranchStrategy {
+    fn select_node<G: BBGraph>(&self, graph: &G) -> Node {
+        match self {
+            BranchStrategy::MostUndirectedEdges => graph
+                .node_with_most_undirected_edges()
+                .or_else(|| graph.node_with_max_out_degree())
+                .unwrap_or(0),
+
+            BranchStrategy::HighestDegree => graph.node_with_max_total_degree().unwrap_or(0),
+
+            BranchStrategy::LowestIndex => 0,
+        }
+    }
+}
+
 /// This is the entry point for the branch and bound recursion. It support arbitrary topologies,
 /// applies some reductions and then recurses on each strongly connected component.
-pub fn branch_and_bound_impl_sccs<G>(
+pub(super) fn branch_and_bound_impl_sccs<G>(
     graph: &G,
     mut lower_bound_incl: Node,
     mut upper_limit_excl: Node,
-    stats: &mut BBStats,
+    search: &mut BBSearch,
 ) -> Option<G::NodeMask>
 where
     G: BBGraph
@@ -22,6 +53,12 @@ where
 {
     // at this point we cannot be sure that the graph is not acyclic
     if upper_limit_excl <= lower_bound_incl {
+        search.stats.count_pruned();
+        return None;
+    }
+
+    // a cancelled search gives up at the next SCC boundary
+    if search.check_cancelled() {
         return None;
     }
 
@@ -29,6 +66,7 @@ where
     let graph = graph.contract_chaining_nodes();
     let loops = graph.nodes_with_loops();
     if loops.count_ones() >= upper_limit_excl {
+        search.stats.count_pruned();
         return None;
     }
     upper_limit_excl -= loops.count_ones();
@@ -42,15 +80,17 @@ where
 
     // since the graph contains at least one cycle, we cannot produce a DFVS with less than one node
     if upper_limit_excl == 1 || lower_bound_incl >= upper_limit_excl {
+        search.stats.count_pruned();
         return None;
     }
 
     // shortcut if the transitive closure is fully connected; then we do not have to search SCCs
     // and can even avoid repeated computation of the transitive closure
     if loops.is_zero() && transitive_closure.has_all_edges() {
-        return branch_and_bound_impl(&graph, lower_bound_incl, upper_limit_excl, stats);
+        return branch_and_bound_impl(&graph, lower_bound_incl, upper_limit_excl, search);
     }
 
+    search.stats.count_scc_decomposition();
     let mut sccs: ArrayVec<G::NodeMask, { G::CAPACITY }> = transitive_closure
         .sccs()
         .filter(|x| x.count_ones() > 1)
@@ -58,9 +98,14 @@ where
 
     // each SCC needs at least one node in the DFVS giving a lower bound
     if sccs.len() as Node >= upper_limit_excl {
+        search.stats.count_pruned();
         return None;
     }
 
+    if sccs.len() > 1 {
+        search.stats.count_scc_split();
+    }
+
     sccs.sort_unstable_by_key(|s| s.count_ones());
     let num_sccs = sccs.len();
     upper_limit_excl -= num_sccs as Node;
@@ -74,7 +119,7 @@ where
             &scc_graph,
             if is_last { lower_bound_incl } else { 1 },
             upper_limit_excl + 1,
-            stats,
+            search,
         )?;
 
         upper_limit_excl -= scc_solution.count_ones() - 1;
@@ -94,10 +139,57 @@ macro_rules! return_if_some {
 }
 
 fn branch_and_bound_impl<G>(
+    graph: &G,
+    lower_bound_incl: Node,
+    upper_bound_excl: Node,
+    search: &mut BBSearch,
+) -> Option<G::NodeMask>
+where
+    G: BBGraph
+        + Any
+        + BBTryCompact<Graph8>
+        + BBTryCompact<Graph16>
+        + BBTryCompact<Graph32>
+        + BBTryCompact<Graph64>,
+    [(); G::CAPACITY]:,
+{
+    let memo_key = search.memoization_enabled().then(|| memo_key(graph));
+    if let Some(key) = &memo_key {
+        if let Some(result) = search.memo_lookup(key, upper_bound_excl) {
+            return result.map(|mask| G::NodeMask::from_u128(mask).unwrap());
+        }
+    }
+
+    search.stats.enter();
+    let solution = branch_and_bound_impl_node(graph, lower_bound_incl, upper_bound_excl, search);
+    search.stats.leave();
+
+    // graphs processed here are strongly connected and have at least two nodes, so a lower
+    // bound of 1 is trivial and the result is a minimum solution (or proves that none exists)
+    if let Some(key) = memo_key {
+        if lower_bound_incl <= 1 && !search.stats.aborted() {
+            search.memo_store(key, solution.map(|mask| mask.as_()), upper_bound_excl);
+        }
+    }
+
+    solution
+}
+
+/// Key of the memoization cache (see [`BBConfig::memo_entries`])
+fn memo_key<G: BBGraph>(graph: &G) -> MemoKey {
+    let rows = graph
+        .vertices()
+        .map(|u| graph.out_neighbors(u as Node).as_())
+        .collect();
+    (graph.len(), rows)
+}
+
+/// Processes a single node of the search tree; see [`branch_and_bound_impl`]
+fn branch_and_bound_impl_node<G>(
     graph: &G,
     mut lower_bound_incl: Node,
     mut upper_bound_excl: Node,
-    stats: &mut BBStats,
+    search: &mut BBSearch,
 ) -> Option<G::NodeMask>
 where
     G: BBGraph
@@ -109,10 +201,17 @@ where
     [(); G::CAPACITY]:,
 {
     debug_assert!(!graph.has_node_with_loop());
-    stats.entered_at(graph.len());
+    search.stats.entered_at(graph.len());
+
+    // once the deadline passed, we do not explore any further; the callers keep the solutions
+    // they already found, but these are not proven to be optimal anymore
+    if search.deadline_passed() {
+        return None;
+    }
 
     // at this point we cannot be sure that the graph is not acyclic, so we have some annoying checks
     if lower_bound_incl >= upper_bound_excl {
+        search.stats.count_pruned();
         return None;
     }
 
@@ -156,10 +255,14 @@ where
     // - branch2: Contract node 0
 
     // To speed things up, we try to choose a good node and make it node 0
-    let node_to_process = graph
-        .node_with_most_undirected_edges()
-        .or_else(|| graph.node_with_max_out_degree())
-        .unwrap_or(0);
+    let node_to_process = search.config.branch_strategy.select_node(graph);
+    log_if!(Level::Trace, || format!(
+        "B&B branching on node {} of {}, bounds [{}, {})",
+        node_to_process,
+        graph.len(),
+        lower_bound_incl,
+        upper_bound_excl
+    ));
     let graph = graph.swap_nodes(0, node_to_process);
 
     let solution1 = {
@@ -170,8 +273,9 @@ where
             &graph.remove_first_node(),
             lower_bound_incl.saturating_sub(1),
             upper_bound_excl - 1,
-            stats,
+            search,
         ) {
+            search.stats.count_ub_improvement();
             lower_bound_incl = sol.count_ones();
             upper_bound_excl = lower_bound_incl + 1;
             sol = (sol << 1) | G::NodeMask::one();
@@ -191,13 +295,14 @@ where
         &graph.contract_first_node(),
         lower_bound_incl,
         upper_bound_excl,
-        stats,
+        search,
     )
     .map(|s| s << 1);
 
     // if solution 2 found a DFVS it is smaller than `ulimit_ex` and therefore strictly
     // smaller than solution 1 (if it exists)
     let best_solution = if solution2.is_some() {
+        search.stats.count_ub_improvement();
         solution2
     } else {
         solution1
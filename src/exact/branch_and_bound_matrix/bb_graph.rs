@@ -138,6 +138,20 @@ pub trait BBGraph: Sized {
             .map(|u| u as Node)
             .max_by_key(|&u| self.out_neighbors(u).count_ones())
     }
+
+    /// Returns a node with maximum total (in- plus out-) degree or None if the graph is empty.
+    /// The in-degrees are read from the bit columns, so no buffer is allocated.
+    fn node_with_max_total_degree(&self) -> Option<Node> {
+        self.vertices().map(|u| u as Node).max_by_key(|&u| {
+            let in_degree: u32 = self
+                .vertices()
+                .map(|v| -> u32 {
+                    ((self.out_neighbors(v as Node) >> (u as usize)) & Self::NodeMask::one()).as_()
+                })
+                .sum();
+            self.out_neighbors(u).count_ones() + in_degree
+        })
+    }
 }
 
 pub trait BBTryCompact<T>: BBGraph
@@ -459,6 +473,23 @@ macro_rules! bbgraph_tests {
                 }
             }
 
+            #[test]
+            fn [<node_with_max_total_degree $n >]() {
+                use rand::Rng;
+                let rng = &mut rand::thread_rng();
+
+                assert!($t::from(&AdjListMatrix::new(0)).node_with_max_total_degree().is_none());
+
+                for _ in 0..100 {
+                    let n = rng.gen_range(1..$t::CAPACITY);
+                    let org_graph : AdjListMatrixIn = crate::random_models::gnp::generate_gnp(rng, $t::CAPACITY as Node, 0.5 / (n as f64));
+                    let degs = org_graph.vertices().map(|u| org_graph.total_degree(u)).collect_vec();
+
+                    let max_node = $t::from(&org_graph).node_with_max_total_degree().unwrap();
+                    assert_eq!(degs[max_node as usize], *degs.iter().max().unwrap());
+                }
+            }
+
             #[test]
             fn [<swap_nodes_ $n >]() {
                 use rand::Rng;
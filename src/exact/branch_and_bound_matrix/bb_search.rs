@@ -0,0 +1,116 @@
+use super::bb_config::BBConfig;
+use super::bb_stats::BBStats;
+use crate::graph::Node;
+use fxhash::FxHashMap;
+use std::sync::atomic::Ordering;
+use std::time::Instant;
+
+/// The clock is only read every `DEADLINE_CHECK_INTERVAL` recursive calls to keep the overhead low
+const DEADLINE_CHECK_INTERVAL: usize = 1024;
+
+/// Identifies a graph of the branch and bound by its number of nodes and its adjacency rows
+pub(super) type MemoKey = (usize, Vec<u128>);
+
+/// What is known about a memoized graph
+#[derive(Clone, Copy, Debug)]
+enum MemoEntry {
+    /// A minimum DFVS as node mask
+    Optimum(u128),
+    /// There exists no DFVS smaller than the value
+    NoneBelow(Node),
+}
+
+/// State of a single search threaded through the recursion: the config, the counters collected
+/// into the caller's [`BBStats`] and the memoization cache (see [`BBConfig::memo_entries`])
+pub(super) struct BBSearch<'a, 'c> {
+    pub(super) config: &'a BBConfig<'c>,
+    pub(super) stats: &'a mut BBStats,
+    memo: Option<FxHashMap<MemoKey, MemoEntry>>,
+}
+
+impl<'a, 'c> BBSearch<'a, 'c> {
+    pub(super) fn new(config: &'a BBConfig<'c>, stats: &'a mut BBStats) -> Self {
+        Self {
+            config,
+            stats,
+            memo: (config.memo_entries > 0).then(FxHashMap::default),
+        }
+    }
+
+    /// Counts an explored node of the search tree and returns true iff the deadline passed.
+    /// Once true, it remains true for the rest of the search.
+    pub(super) fn deadline_passed(&mut self) -> bool {
+        let explored = self.stats.count_explored();
+        if let Some(deadline) = self.config.deadline {
+            if !self.stats.timed_out()
+                && explored.is_multiple_of(DEADLINE_CHECK_INTERVAL)
+                && Instant::now() >= deadline
+            {
+                self.stats.mark_timed_out();
+            }
+        }
+        self.stats.timed_out()
+    }
+
+    /// Returns true iff the cancel flag was set. Once true, it remains true for the rest of the
+    /// search.
+    pub(super) fn check_cancelled(&mut self) -> bool {
+        if !self.stats.cancelled() {
+            if let Some(cancel) = self.config.cancel {
+                if cancel.load(Ordering::Relaxed) {
+                    self.stats.mark_cancelled();
+                }
+            }
+        }
+        self.stats.cancelled()
+    }
+
+    pub(super) fn memoization_enabled(&self) -> bool {
+        self.memo.is_some()
+    }
+
+    /// Returns the result of a subproblem with the given key if it is implied by the cache, i.e.
+    /// `Some(Some(mask))` for a minimum solution smaller than `upper_bound_excl`, `Some(None)` if
+    /// there is no such solution, and `None` if the cache does not know.
+    pub(super) fn memo_lookup(
+        &mut self,
+        key: &MemoKey,
+        upper_bound_excl: Node,
+    ) -> Option<Option<u128>> {
+        let result = match self.memo.as_ref()?.get(key)? {
+            MemoEntry::Optimum(mask) if mask.count_ones() < upper_bound_excl => Some(Some(*mask)),
+            MemoEntry::Optimum(_) => Some(None),
+            MemoEntry::NoneBelow(bound) if upper_bound_excl <= *bound => Some(None),
+            MemoEntry::NoneBelow(_) => None,
+        };
+        if result.is_some() {
+            self.stats.count_memo_hit();
+        }
+        result
+    }
+
+    /// Stores that the subproblem with the given key has the minimum solution `solution` or, if
+    /// it is `None`, no solution smaller than `upper_bound_excl`
+    pub(super) fn memo_store(
+        &mut self,
+        key: MemoKey,
+        solution: Option<u128>,
+        upper_bound_excl: Node,
+    ) {
+        let max_entries = self.config.memo_entries;
+        if let Some(memo) = self.memo.as_mut() {
+            if memo.len() >= max_entries && !memo.contains_key(&key) {
+                return;
+            }
+            let entry = match (memo.get(&key), solution) {
+                (Some(MemoEntry::Optimum(_)), _) => return,
+                (_, Some(mask)) => MemoEntry::Optimum(mask),
+                (Some(MemoEntry::NoneBelow(bound)), None) => {
+                    MemoEntry::NoneBelow(upper_bound_excl.max(*bound))
+                }
+                (None, None) => MemoEntry::NoneBelow(upper_bound_excl),
+            };
+            memo.insert(key, entry);
+        }
+    }
+}
@@ -1,17 +1,31 @@
 #[cfg(feature = "bb-stats")]
 use crate::bench::io::keyed_buffer::KeyedBuffer;
+use crate::graph::Node;
 
+/// Counters collected by the matrix branch and bound; the settings of the search are passed
+/// separately via [`super::bb_config::BBConfig`]
 #[derive(Clone, Debug)]
 #[cfg_attr(not(feature = "bb-stats"), derive(Default))]
 pub struct BBStats {
     #[cfg(feature = "bb-stats")]
     entered_at: [usize; 128],
+    explored: usize,
+    timed_out: bool,
+    cancelled: bool,
+    lower_bound: Node,
+    scc_decompositions: usize,
+    scc_splits: usize,
+    depth: usize,
+    max_depth: usize,
+    ub_improvements: usize,
+    pruned: usize,
+    memo_hits: usize,
 }
 
 #[cfg(not(feature = "bb-stats"))]
 impl BBStats {
     pub fn new() -> Self {
-        Self {}
+        Self::default()
     }
 
     pub fn entered_at(&mut self, _n: usize) {}
@@ -22,6 +36,17 @@ impl Default for BBStats {
     fn default() -> Self {
         Self {
             entered_at: [0usize; 128],
+            explored: 0,
+            timed_out: false,
+            cancelled: false,
+            lower_bound: 0,
+            scc_decompositions: 0,
+            scc_splits: 0,
+            depth: 0,
+            max_depth: 0,
+            ub_improvements: 0,
+            pruned: 0,
+            memo_hits: 0,
         }
     }
 }
@@ -54,3 +79,129 @@ impl BBStats {
         }
     }
 }
+
+impl BBStats {
+    /// Counts an explored node of the search tree and returns the number of nodes explored so far
+    pub fn count_explored(&mut self) -> usize {
+        self.explored += 1;
+        self.explored
+    }
+
+    /// Returns true iff a search using these stats was aborted due to its deadline
+    pub fn timed_out(&self) -> bool {
+        self.timed_out
+    }
+
+    pub fn mark_timed_out(&mut self) {
+        self.timed_out = true;
+    }
+
+    /// Returns true iff a search using these stats was aborted due to its cancel flag
+    pub fn cancelled(&self) -> bool {
+        self.cancelled
+    }
+
+    pub fn mark_cancelled(&mut self) {
+        self.cancelled = true;
+    }
+
+    /// Returns true iff a search using these stats was aborted due to its deadline or cancel flag
+    pub fn aborted(&self) -> bool {
+        self.timed_out || self.cancelled
+    }
+
+    /// Number of nodes of the search tree explored so far
+    pub fn explored(&self) -> usize {
+        self.explored
+    }
+
+    /// Best proven lower bound on the size of a minimum DFVS known so far
+    pub fn lower_bound(&self) -> Node {
+        self.lower_bound
+    }
+
+    /// Raises the lower bound to `lower_bound` unless a better one is already known
+    pub fn update_lower_bound(&mut self, lower_bound: Node) {
+        self.lower_bound = self.lower_bound.max(lower_bound);
+    }
+
+    /// Number of times a graph was split into its non-trivial SCCs which were then solved
+    /// independently
+    pub fn scc_decompositions(&self) -> usize {
+        self.scc_decompositions
+    }
+
+    pub fn count_scc_decomposition(&mut self) {
+        self.scc_decompositions += 1;
+    }
+
+    /// Number of SCC decompositions (see [`BBStats::scc_decompositions`]) that yielded more than
+    /// one non-trivial SCC, e.g. since deleting or contracting a node split the graph
+    pub fn scc_splits(&self) -> usize {
+        self.scc_splits
+    }
+
+    pub fn count_scc_split(&mut self) {
+        self.scc_splits += 1;
+    }
+
+    /// Maximum recursion depth of the branching reached so far
+    pub fn max_depth(&self) -> usize {
+        self.max_depth
+    }
+
+    /// Has to be called when entering a branching node; see [`BBStats::leave`]
+    pub fn enter(&mut self) {
+        self.depth += 1;
+        self.max_depth = self.max_depth.max(self.depth);
+    }
+
+    /// Has to be called when leaving a branching node previously entered via [`BBStats::enter`]
+    pub fn leave(&mut self) {
+        self.depth -= 1;
+    }
+
+    /// Number of times a branch found a solution improving the upper bound of its caller
+    pub fn ub_improvements(&self) -> usize {
+        self.ub_improvements
+    }
+
+    pub fn count_ub_improvement(&mut self) {
+        self.ub_improvements += 1;
+    }
+
+    /// Number of nodes of the search tree pruned since their lower bound reached the upper bound
+    pub fn pruned(&self) -> usize {
+        self.pruned
+    }
+
+    pub fn count_pruned(&mut self) {
+        self.pruned += 1;
+    }
+
+    /// Number of subproblems answered by the memoization cache
+    pub fn memo_hits(&self) -> usize {
+        self.memo_hits
+    }
+
+    pub fn count_memo_hit(&mut self) {
+        self.memo_hits += 1;
+    }
+
+    /// Accumulates the counters of `other` (e.g. collected by another thread) into self
+    pub fn merge(&mut self, other: &BBStats) {
+        #[cfg(feature = "bb-stats")]
+        for (a, b) in self.entered_at.iter_mut().zip(other.entered_at.iter()) {
+            *a += b;
+        }
+        self.explored += other.explored;
+        self.scc_decompositions += other.scc_decompositions;
+        self.scc_splits += other.scc_splits;
+        self.max_depth = self.max_depth.max(self.depth + other.max_depth);
+        self.ub_improvements += other.ub_improvements;
+        self.pruned += other.pruned;
+        self.memo_hits += other.memo_hits;
+        self.timed_out |= other.timed_out;
+        self.cancelled |= other.cancelled;
+    }
+}
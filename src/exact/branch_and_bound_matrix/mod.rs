@@ -1,14 +1,20 @@
 use crate::algorithm::*;
+use crate::bitset::BitSet;
 use crate::graph::*;
 use crate::utils::*;
 use itertools::Itertools;
 use num::cast::AsPrimitive;
 use num::PrimInt;
+use rayon::prelude::*;
+use std::sync::atomic::AtomicBool;
+use std::time::Instant;
 
 #[cfg(target_arch = "x86_64")]
 mod avx2;
+pub mod bb_config;
 pub mod bb_core;
 pub mod bb_graph;
+mod bb_search;
 pub mod bb_stats;
 mod generic_int_graph;
 mod scc_iterator;
@@ -18,11 +24,15 @@ pub use bb_graph::*;
 pub use generic_int_graph::*;
 use scc_iterator::*;
 
+pub use bb_config::BBConfig;
+use bb_search::BBSearch;
 use bb_stats::BBStats;
 
 pub struct BranchAndBoundMatrix<'a, G> {
     graph: &'a G,
     solution: Option<Vec<Node>>,
+    deadline: Option<Instant>,
+    proven: bool,
 }
 
 impl<'a, G> BranchAndBoundMatrix<'a, G>
@@ -33,8 +43,17 @@ where
         Self {
             graph,
             solution: None,
+            deadline: None,
+            proven: false,
         }
     }
+
+    /// If the search does not finish before `deadline`, the algorithm keeps the best solution
+    /// found so far but is not completed. Use [`IterativeAlgorithm::run_until_timeout`] rather
+    /// than [`TerminatingIterativeAlgorithm::run_to_completion`] in this case.
+    pub fn set_deadline(&mut self, deadline: Instant) {
+        self.deadline = Some(deadline);
+    }
 }
 
 impl<'a, G> IterativeAlgorithm for BranchAndBoundMatrix<'a, G>
@@ -42,12 +61,31 @@ where
     G: 'a + AdjacencyList,
 {
     fn execute_step(&mut self) {
-        self.solution = branch_and_bound_matrix(self.graph, None);
+        match self.deadline {
+            None => {
+                self.solution = branch_and_bound_matrix(self.graph, None);
+                self.proven = true;
+            }
+            Some(deadline) => {
+                if self.solution.is_some() && Instant::now() >= deadline {
+                    return; // we would only recompute the same fallback
+                }
+                let (solution, proven) = branch_and_bound_matrix_deadline(
+                    self.graph,
+                    None,
+                    deadline,
+                    &BBConfig::default(),
+                    &mut BBStats::new(),
+                );
+                self.solution = solution;
+                self.proven = proven;
+            }
+        }
         assert!(self.solution.is_some());
     }
 
     fn is_completed(&self) -> bool {
-        self.solution.is_some()
+        self.solution.is_some() && self.proven
     }
 
     fn best_known_solution(&mut self) -> Option<&[Node]> {
@@ -63,7 +101,12 @@ pub fn branch_and_bound_matrix<G: AdjacencyList>(
     graph: &G,
     upper_bound: Option<Node>,
 ) -> Option<Vec<Node>> {
-    branch_and_bound_matrix_stats(graph, upper_bound, &mut BBStats::new())
+    branch_and_bound_matrix_stats(
+        graph,
+        upper_bound,
+        &BBConfig::default(),
+        &mut BBStats::new(),
+    )
 }
 
 /// Return the smallest dfvs with up to `upper_bound` nodes (inclusive).
@@ -72,79 +115,361 @@ pub fn branch_and_bound_matrix_lower<G: AdjacencyList>(
     lower_bound: Node,
     upper_bound: Option<Node>,
 ) -> Option<Vec<Node>> {
-    branch_and_bound_matrix_lower_stats(graph, lower_bound, upper_bound, &mut BBStats::new())
+    branch_and_bound_matrix_lower_stats(
+        graph,
+        lower_bound,
+        upper_bound,
+        &BBConfig::default(),
+        &mut BBStats::new(),
+    )
+}
+
+/// Same as [`branch_and_bound_matrix`], but additionally returns the acyclic subgraph induced by
+/// the nodes not in the solution. Node i of this residual graph is the i-th smallest kept node.
+pub fn branch_and_bound_matrix_with_residual<G: AdjacencyList>(
+    graph: &G,
+    upper_bound: Option<Node>,
+) -> Option<(Vec<Node>, AdjListMatrix)> {
+    let solution = branch_and_bound_matrix(graph, upper_bound)?;
+
+    let matrix = AdjListMatrix::from_edges(graph.len(), graph.edges_iter());
+    let (residual, _) = matrix.vertex_induced(&BitSet::new_all_set_but(
+        graph.len(),
+        solution.iter().copied(),
+    ));
+    debug_assert!(residual.is_acyclic());
+
+    Some((solution, residual))
 }
 
+/// Same as [`branch_and_bound_matrix_stats`], but additionally returns a proven lower bound on
+/// the size of a minimum DFVS, which is also available via [`BBStats::lower_bound`]. If the search
+/// completes, the lower bound matches the size of the solution (or exceeds `upper_bound` if no
+/// solution is returned). If it is aborted (e.g. due to a deadline set in `config`), the number
+/// of non-trivial SCCs is reported, as each of them contains at least one node of any DFVS.
+pub fn branch_and_bound_matrix_with_bounds<G: AdjacencyList>(
+    graph: &G,
+    upper_bound: Option<Node>,
+    config: &BBConfig,
+    stats: &mut BBStats,
+) -> (Option<Vec<Node>>, Node) {
+    let scc_lower_bound = graph.strongly_connected_components_no_singletons().len() as Node;
+    stats.update_lower_bound(scc_lower_bound);
+
+    let solution =
+        branch_and_bound_matrix_lower_stats(graph, scc_lower_bound, upper_bound, config, stats);
+
+    if !stats.aborted() {
+        match &solution {
+            Some(solution) => stats.update_lower_bound(solution.len() as Node),
+            None => stats.update_lower_bound(upper_bound.map_or(0, |ub| ub + 1)),
+        }
+    }
+
+    (solution, stats.lower_bound())
+}
+
+/// Seeds the search with a known DFVS `initial` (e.g. computed by one of the heuristics) and only
+/// searches for strictly smaller solutions. Returns `initial` if it is already optimal.
+pub fn branch_and_bound_matrix_warm_start<G: AdjacencyList>(
+    graph: &G,
+    initial: &[Node],
+    config: &BBConfig,
+    stats: &mut BBStats,
+) -> Vec<Node> {
+    debug_assert!(is_dfvs(graph, initial));
+
+    if initial.is_empty() {
+        return Vec::new();
+    }
+
+    branch_and_bound_matrix_stats(graph, Some(initial.len() as Node - 1), config, stats)
+        .unwrap_or_else(|| initial.to_vec())
+}
+
+/// Same as [`branch_and_bound_matrix_stats`], but gives up once `deadline` passed (overriding
+/// [`BBConfig::deadline`]). Returns the
+/// best solution known at this point together with a flag indicating whether it is proven to be
+/// optimal (or, if no solution is returned, whether it is proven that none exists within
+/// `upper_bound`). If the search is aborted before any DFVS was found, all nodes in non-trivial
+/// SCCs are returned as a (weak) feasible solution.
+pub fn branch_and_bound_matrix_deadline<G: AdjacencyList>(
+    graph: &G,
+    upper_bound: Option<Node>,
+    deadline: Instant,
+    config: &BBConfig,
+    stats: &mut BBStats,
+) -> (Option<Vec<Node>>, bool) {
+    let config = BBConfig {
+        deadline: Some(deadline),
+        ..*config
+    };
+    let solution = branch_and_bound_matrix_stats(graph, upper_bound, &config, stats);
+    solution_or_fallback(graph, upper_bound, solution, stats.aborted())
+}
+
+/// Same as [`branch_and_bound_matrix_stats`], but gives up (at the next SCC boundary) once
+/// `cancel` is set (overriding [`BBConfig::cancel`]), e.g. by another thread or a signal handler
+/// such as [`crate::signal_handling::SIGINT`]. As with [`branch_and_bound_matrix_deadline`], the
+/// result of a cancelled search is a feasible but possibly non-optimal solution, which is
+/// indicated by the returned flag being false.
+pub fn branch_and_bound_matrix_cancellable<G: AdjacencyList>(
+    graph: &G,
+    upper_bound: Option<Node>,
+    config: &BBConfig,
+    stats: &mut BBStats,
+    cancel: &AtomicBool,
+) -> (Option<Vec<Node>>, bool) {
+    let config = BBConfig {
+        cancel: Some(cancel),
+        ..*config
+    };
+    let solution = branch_and_bound_matrix_stats(graph, upper_bound, &config, stats);
+    solution_or_fallback(graph, upper_bound, solution, stats.aborted())
+}
+
+/// Returns proven solutions as is; if the search was aborted before finding any solution, we
+/// fall back to all nodes in non-trivial SCCs.
+fn solution_or_fallback<G: AdjacencyList>(
+    graph: &G,
+    upper_bound: Option<Node>,
+    solution: Option<Vec<Node>>,
+    aborted: bool,
+) -> (Option<Vec<Node>>, bool) {
+    if !aborted {
+        return (solution, true);
+    }
+
+    if solution.is_some() {
+        return (solution, false);
+    }
+
+    let fallback = graph
+        .strongly_connected_components_no_singletons()
+        .into_iter()
+        .flatten()
+        .sorted()
+        .collect_vec();
+
+    if upper_bound.is_none_or(|ub| fallback.len() as Node <= ub) {
+        (Some(fallback), false)
+    } else {
+        (None, false)
+    }
+}
+
+/// Return the smallest dfvs with up to `upper_bound` nodes (inclusive). With feature `bb-parallel`,
+/// the strongly connected components are solved in parallel (see [`branch_and_bound_matrix_par_stats`]).
 pub fn branch_and_bound_matrix_stats<G: AdjacencyList>(
     graph: &G,
     upper_bound: Option<Node>,
+    config: &BBConfig,
     stats: &mut BBStats,
 ) -> Option<Vec<Node>> {
-    branch_and_bound_matrix_lower_stats(graph, 0, upper_bound, stats)
+    #[cfg(feature = "bb-parallel")]
+    return branch_and_bound_matrix_par_stats(graph, upper_bound, config, stats);
+
+    #[cfg(not(feature = "bb-parallel"))]
+    branch_and_bound_matrix_lower_stats(graph, 0, upper_bound, config, stats)
 }
 
+/// Return the smallest dfvs with up to `upper_bound` nodes (inclusive). The non-trivial strongly
+/// connected components are solved independently in parallel, each with its own [`BBStats`]
+/// which are merged into `stats` at the end. Since each SCC is processed separately, only
+/// the SCCs (rather than the whole graph) need to be small enough for the matrix representation.
+pub fn branch_and_bound_matrix_par_stats<G: AdjacencyList>(
+    graph: &G,
+    upper_bound: Option<Node>,
+    config: &BBConfig,
+    stats: &mut BBStats,
+) -> Option<Vec<Node>> {
+    let sccs = graph.strongly_connected_components_no_singletons();
+    let upper_bound = upper_bound.unwrap_or_else(|| graph.number_of_nodes());
+    if sccs.len() as Node > upper_bound {
+        return None;
+    }
+    // each of the other SCCs requires at least one node
+    let scc_upper_bound = upper_bound + 1 - sccs.len() as Node;
+
+    // the subgraphs are extracted sequentially, so we do not require the input graph to be `Sync`
+    let scc_graphs = scc_subgraphs(graph, sccs);
+
+    stats.count_scc_decomposition();
+    if scc_graphs.len() > 1 {
+        stats.count_scc_split();
+    }
+    let results: Vec<(Option<Vec<Node>>, BBStats)> = scc_graphs
+        .par_iter()
+        .map(|(scc, scc_graph)| {
+            let mut local_stats = BBStats::new();
+            let solution = branch_and_bound_matrix_lower_stats(
+                scc_graph,
+                1,
+                Some(scc_upper_bound),
+                config,
+                &mut local_stats,
+            )
+            .map(|sol| sol.into_iter().map(|u| scc[u as usize]).collect_vec());
+
+            (solution, local_stats)
+        })
+        .collect();
+
+    for (_, local_stats) in &results {
+        stats.merge(local_stats);
+    }
+
+    let mut solution = Vec::new();
+    for (scc_solution, _) in results {
+        solution.extend(scc_solution?);
+    }
+
+    if solution.len() as Node > upper_bound {
+        return None;
+    }
+
+    solution.sort_unstable();
+    Some(solution)
+}
+
+/// Returns each SCC together with the subgraph it induces; node i of the subgraph is `scc[i]`
+fn scc_subgraphs<G: AdjacencyList>(graph: &G, sccs: Vec<Vec<Node>>) -> Vec<(Vec<Node>, AdjArray)> {
+    let mut new_id = vec![Node::MAX; graph.len()];
+    sccs.into_iter()
+        .map(|scc| {
+            for (i, &u) in scc.iter().enumerate() {
+                new_id[u as usize] = i as Node;
+            }
+            let mut scc_graph = AdjArray::new(scc.len());
+            for &u in &scc {
+                for v in graph.out_neighbors(u) {
+                    if new_id[v as usize] != Node::MAX {
+                        scc_graph.add_edge(new_id[u as usize], new_id[v as usize]);
+                    }
+                }
+            }
+            for &u in &scc {
+                new_id[u as usize] = Node::MAX;
+            }
+            (scc, scc_graph)
+        })
+        .collect_vec()
+}
+
+/// Computes a minimum DFVS by solving each non-trivial SCC (i.e. with at least two nodes or a
+/// self-loop) independently and returns the sorted union of their solutions. Nodes of trivial
+/// SCCs lie on no cycle and are never part of the result. As only the SCCs are converted into
+/// the matrix representation, the graph may be arbitrarily large as long as each SCC has at
+/// most 128 nodes. If the search of an SCC is aborted (e.g. due to a deadline set in `config`),
+/// all nodes of this SCC are taken, so the result is always a DFVS, but then not necessarily a
+/// minimum one (see [`BBStats::aborted`]).
+/// ** Panics if an SCC has more than 128 nodes **
+pub fn solve_by_sccs<G: AdjacencyList>(
+    graph: &G,
+    config: &BBConfig,
+    stats: &mut BBStats,
+) -> Vec<Node> {
+    let sccs = graph.strongly_connected_components_no_singletons();
+    stats.count_scc_decomposition();
+    if sccs.len() > 1 {
+        stats.count_scc_split();
+    }
+
+    let mut solution = Vec::new();
+    for (scc, scc_graph) in scc_subgraphs(graph, sccs) {
+        match branch_and_bound_matrix_lower_stats(&scc_graph, 1, None, config, stats) {
+            Some(scc_solution) => {
+                solution.extend(scc_solution.into_iter().map(|u| scc[u as usize]))
+            }
+            None => solution.extend(scc),
+        }
+    }
+
+    solution.sort_unstable();
+    solution
+}
+
+/// The adjacency rows are stored in the narrowest of the [`GenericIntGraph`] variants (8 up to
+/// 128 bits) that fits the number of nodes and is at least as wide as requested by
+/// [`BBConfig::min_width`].
+/// ** Panics if the graph has more than 128 nodes **
 pub fn branch_and_bound_matrix_lower_stats<G: AdjacencyList>(
     graph: &G,
     lower_bound: Node,
     upper_bound: Option<Node>,
+    config: &BBConfig,
     stats: &mut BBStats,
 ) -> Option<Vec<Node>> {
     fn solution_to_vec<T: IntegerIterators>(s: Option<T>) -> Option<Vec<Node>> {
         s.map(|s| s.iter_ones().map(|x| x as Node).collect_vec())
     }
 
+    assert!(
+        graph.len() <= 128,
+        "The matrix branch and bound supports at most 128 nodes"
+    );
+
     let upper_bound = upper_bound.unwrap_or_else(|| graph.number_of_nodes()) + 1;
-    if graph.len() > 64 {
-        let graph = Graph128::from(graph);
-        solution_to_vec(branch_and_bound_impl_sccs(
-            &graph,
-            lower_bound,
-            upper_bound,
-            stats,
-        ))
-    } else if graph.len() > 32 {
-        let graph = Graph64::from(graph);
-        solution_to_vec(branch_and_bound_impl_sccs(
-            &graph,
-            lower_bound,
-            upper_bound,
-            stats,
-        ))
-    } else if graph.len() > 16 {
-        let graph = Graph32::from(graph);
-        solution_to_vec(branch_and_bound_impl_sccs(
-            &graph,
-            lower_bound,
-            upper_bound,
-            stats,
-        ))
-    } else if graph.len() > 8 {
-        let graph = Graph16::from(graph);
-        solution_to_vec(branch_and_bound_impl_sccs(
-            &graph,
-            lower_bound,
-            upper_bound,
-            stats,
-        ))
-    } else {
-        let graph = Graph8::from(graph);
-        solution_to_vec(branch_and_bound_impl_sccs(
-            &graph,
-            lower_bound,
-            upper_bound,
-            stats,
-        ))
+    let search = &mut BBSearch::new(config, stats);
+    match config.row_width(graph.len()) {
+        8 => {
+            let graph = Graph8::from(graph);
+            solution_to_vec(branch_and_bound_impl_sccs(
+                &graph,
+                lower_bound,
+                upper_bound,
+                search,
+            ))
+        }
+        16 => {
+            let graph = Graph16::from(graph);
+            solution_to_vec(branch_and_bound_impl_sccs(
+                &graph,
+                lower_bound,
+                upper_bound,
+                search,
+            ))
+        }
+        32 => {
+            let graph = Graph32::from(graph);
+            solution_to_vec(branch_and_bound_impl_sccs(
+                &graph,
+                lower_bound,
+                upper_bound,
+                search,
+            ))
+        }
+        64 => {
+            let graph = Graph64::from(graph);
+            solution_to_vec(branch_and_bound_impl_sccs(
+                &graph,
+                lower_bound,
+                upper_bound,
+                search,
+            ))
+        }
+        _ => {
+            let graph = Graph128::from(graph);
+            solution_to_vec(branch_and_bound_impl_sccs(
+                &graph,
+                lower_bound,
+                upper_bound,
+                search,
+            ))
+        }
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::bitset::BitSet;
+    use crate::graph::generators::GeneratorSubstructures;
+    use crate::heuristics::greedy::{greedy_dfvs, greedy_dfvs_with_pruning, MaxDegreeSelector};
+    use crate::heuristics::lowerbound_circuits::cycle_packing_lower_bound;
     use crate::random_models::gnp::generate_gnp;
     use crate::random_models::planted_cycles::generate_planted_cycles;
     use rand::prelude::SliceRandom;
-    use rand::SeedableRng;
+    use rand::{Rng, SeedableRng};
     use rand_pcg::Pcg64Mcg;
 
     #[test]
@@ -324,6 +649,108 @@ mod tests {
         );
     }
 
+    #[test]
+    fn bb_with_residual() {
+        let mut gen = Pcg64Mcg::seed_from_u64(8);
+        for n in [0, 1, 10, 30] {
+            let graph: AdjListMatrix = generate_gnp(&mut gen, n, 0.15);
+            let (solution, residual) = branch_and_bound_matrix_with_residual(&graph, None).unwrap();
+            assert_eq!(
+                solution.len(),
+                branch_and_bound_matrix(&graph, None).unwrap().len()
+            );
+            assert_eq!(residual.len(), graph.len() - solution.len());
+            assert!(residual.is_acyclic());
+
+            let keep = BitSet::new_all_set_but(graph.len(), solution.iter().copied());
+            assert_eq!(
+                residual.edges_vec(),
+                graph.induced_subgraph(&keep).0.edges_vec()
+            );
+        }
+
+        // a 2-cycle needs one node, which exceeds an upper bound of 0
+        let graph = AdjListMatrix::from(&[(0, 1), (1, 0), (1, 2)]);
+        assert!(branch_and_bound_matrix_with_residual(&graph, Some(0)).is_none());
+        let (solution, residual) = branch_and_bound_matrix_with_residual(&graph, Some(1)).unwrap();
+        assert_eq!(solution.len(), 1);
+        assert_eq!(residual.len(), 2);
+        assert!(residual.is_acyclic());
+    }
+
+    #[test]
+    fn bb_solve_by_sccs() {
+        // two disjoint cycles joined by a one-way edge, a self-loop and an acyclic tail
+        let mut graph = AdjListMatrix::new(12);
+        graph.connect_cycle([0, 1, 2, 3]);
+        graph.connect_cycle([4, 5, 6]);
+        graph.connect_cycle([6, 5, 4]);
+        graph.add_edge(3, 4);
+        graph.add_edge(7, 7);
+        graph.connect_path([6, 8, 9]);
+
+        let cycle = AdjListMatrix::from(&[(0, 1), (1, 2), (2, 3), (3, 0)]);
+        let bidirected_triangle =
+            AdjListMatrix::from(&[(0, 1), (1, 0), (1, 2), (2, 1), (2, 0), (0, 2)]);
+        let expected_size = branch_and_bound_matrix(&cycle, None).unwrap().len()
+            + branch_and_bound_matrix(&bidirected_triangle, None)
+                .unwrap()
+                .len()
+            + 1;
+
+        let mut stats = BBStats::new();
+        let solution = solve_by_sccs(&graph, &BBConfig::default(), &mut stats);
+        assert_eq!(solution.len(), expected_size);
+        assert_eq!(solution.len(), 4);
+        assert!(is_dfvs(&graph, &solution));
+        assert!(solution.contains(&7));
+        assert!(solution.iter().all(|&u| u < 8));
+        assert!(stats.scc_splits() > 0);
+
+        // the graph is too large for the matrix representation, but each SCC is small
+        let mut large = AdjArray::new(1000);
+        for i in 0..100 {
+            large.connect_cycle((10 * i)..(10 * i + 5));
+            large.add_edge(10 * i + 4, 10 * i + 5);
+        }
+        let solution = solve_by_sccs(&large, &BBConfig::default(), &mut BBStats::new());
+        assert_eq!(solution.len(), 100);
+        assert!(is_dfvs(&large, &solution));
+    }
+
+    #[test]
+    fn bb_scc_splits() {
+        // two bidirected triangles joined by the hub 0; deleting the hub splits the graph
+        let mut graph = AdjListMatrix::new(7);
+        graph.connect_cycle([1, 2, 3]);
+        graph.connect_cycle([3, 2, 1]);
+        graph.connect_cycle([4, 5, 6]);
+        graph.connect_cycle([6, 5, 4]);
+        for u in 1..7 {
+            graph.add_edge(0, u);
+            graph.add_edge(u, 0);
+        }
+        assert_eq!(graph.strongly_connected_components().len(), 1);
+
+        let mut stats = BBStats::new();
+        let solution =
+            branch_and_bound_matrix_stats(&graph, None, &BBConfig::default(), &mut stats).unwrap();
+        assert_eq!(solution.len(), 5);
+        assert!(stats.scc_splits() > 0);
+        assert!(stats.scc_splits() <= stats.scc_decompositions());
+
+        // a single cycle never splits
+        let mut cycle = AdjListMatrix::new(6);
+        cycle.connect_cycle(cycle.vertices_range());
+        let mut stats = BBStats::new();
+        branch_and_bound_matrix_stats(&cycle, None, &BBConfig::default(), &mut stats).unwrap();
+        assert_eq!(stats.scc_splits(), 0);
+
+        let mut merged = BBStats::new();
+        merged.merge(&stats);
+        assert_eq!(merged.scc_splits(), stats.scc_splits());
+    }
+
     #[test]
     fn bb_generated_tests() {
         // The results were generated by the branch_and_bound implementation in MR19.
@@ -345,21 +772,434 @@ mod tests {
                     }
 
                     let solution = branch_and_bound_matrix(&graph, None).unwrap();
-                    let solution_mask = {
-                        let mut set = BitSet::new_all_set(graph.len());
-                        for node in &solution {
-                            set.unset_bit(*node as usize);
-                        }
-                        set
-                    };
-
                     assert_eq!(solution.len(), solution_sizes[((n - 10) * 3 + i) as usize]);
-                    assert!(graph.vertex_induced(&solution_mask).0.is_acyclic());
+                    assert!(is_dfvs(&graph, &solution));
                 }
             }
         }
     }
 
+    #[test]
+    fn bb_warm_start() {
+        let mut gen = Pcg64Mcg::seed_from_u64(123);
+        let mut explored_cold = 0;
+        let mut explored_warm = 0;
+
+        for n in 15..=21 {
+            for _ in 0..3 {
+                let p = 5.0 / n as f64;
+                let mut graph: AdjArrayIn = generate_gnp(&mut gen, n, p);
+                for i in graph.vertices_range() {
+                    graph.try_remove_edge(i, i);
+                }
+
+                let mut stats = BBStats::new();
+                let cold =
+                    branch_and_bound_matrix_stats(&graph, None, &BBConfig::default(), &mut stats)
+                        .unwrap();
+                explored_cold += stats.explored();
+
+                let initial: Vec<Node> = greedy_dfvs::<MaxDegreeSelector<_>, _, _>(graph.clone());
+                assert!(initial.len() >= cold.len());
+
+                let mut stats = BBStats::new();
+                let warm = branch_and_bound_matrix_warm_start(
+                    &graph,
+                    &initial,
+                    &BBConfig::default(),
+                    &mut stats,
+                );
+                explored_warm += stats.explored();
+
+                assert_eq!(warm.len(), cold.len());
+            }
+        }
+
+        assert!(
+            explored_warm < explored_cold,
+            "warm: {} cold: {}",
+            explored_warm,
+            explored_cold
+        );
+    }
+
+    #[test]
+    fn bb_with_bounds() {
+        for k in 1..=6 {
+            let mut graph = AdjArray::new(2 * k);
+            for i in 0..k as Node {
+                graph.connect_cycle([2 * i, 2 * i + 1]);
+            }
+
+            let mut stats = BBStats::new();
+            let (solution, lower_bound) =
+                branch_and_bound_matrix_with_bounds(&graph, None, &BBConfig::default(), &mut stats);
+            assert_eq!(lower_bound, k as Node);
+            assert_eq!(solution.unwrap().len(), k);
+            assert_eq!(stats.lower_bound(), k as Node);
+
+            let (solution, lower_bound) = branch_and_bound_matrix_with_bounds(
+                &graph,
+                Some(k as Node - 1),
+                &BBConfig::default(),
+                &mut BBStats::new(),
+            );
+            assert!(solution.is_none());
+            assert_eq!(lower_bound, k as Node);
+        }
+
+        // aborted search still yields a valid lower bound
+        let mut gen = Pcg64Mcg::seed_from_u64(1);
+        let graph: AdjArray = generate_gnp(&mut gen, 128, 0.05);
+        let config = BBConfig::with_deadline(Instant::now());
+        let mut stats = BBStats::new();
+        let (_, lower_bound) =
+            branch_and_bound_matrix_with_bounds(&graph, None, &config, &mut stats);
+        assert!(stats.timed_out());
+        assert_eq!(
+            lower_bound,
+            graph.strongly_connected_components_no_singletons().len() as Node
+        );
+    }
+
+    #[test]
+    fn bb_single_large_scc() {
+        // a Hamiltonian cycle on 100 nodes with 10 disjoint 2-cycles attached, so the optimum
+        // picks one node of each 2-cycle
+        let n = 100;
+        let mut graph = AdjListMatrix::new(n);
+        graph.connect_cycle(0..n as Node);
+        for i in (0..20).step_by(2) {
+            graph.add_edge(i + 1, i);
+        }
+        assert_eq!(graph.strongly_connected_components().len(), 1);
+
+        let solution = branch_and_bound_matrix(&graph, None).unwrap();
+        assert_eq!(solution.len(), 10);
+        assert!(is_dfvs(&graph, &solution));
+
+        // additional random chords
+        let mut gen = Pcg64Mcg::seed_from_u64(7);
+        for _ in 0..5 {
+            let mut graph = graph.clone();
+            for _ in 0..15 {
+                let u = gen.gen_range(0..n as Node);
+                let v = gen.gen_range(0..n as Node);
+                graph.try_add_edge(u, v);
+            }
+
+            let solution = branch_and_bound_matrix(&graph, None).unwrap();
+            assert!(is_dfvs(&graph, &solution));
+            assert!(solution.len() >= cycle_packing_lower_bound(&graph) as usize);
+            assert!(solution.len() <= greedy_dfvs_with_pruning(&graph).len());
+        }
+    }
+
+    #[test]
+    fn bb_branch_strategies() {
+        let mut gen = Pcg64Mcg::seed_from_u64(4);
+        let strategies = [
+            BranchStrategy::MostUndirectedEdges,
+            BranchStrategy::HighestDegree,
+            BranchStrategy::LowestIndex,
+        ];
+        assert_eq!(BBConfig::default().branch_strategy, strategies[0]);
+
+        let mut explored_differ = false;
+        for _ in 0..5 {
+            let graph: AdjListMatrix = generate_gnp(&mut gen, 30, 0.15);
+            let optimum = branch_and_bound_matrix(&graph, None).unwrap();
+
+            let mut explored = Vec::new();
+            for strategy in strategies {
+                let config = BBConfig {
+                    branch_strategy: strategy,
+                    ..Default::default()
+                };
+                let mut stats = BBStats::new();
+                let solution =
+                    branch_and_bound_matrix_stats(&graph, None, &config, &mut stats).unwrap();
+                assert_eq!(solution.len(), optimum.len());
+                assert!(is_dfvs(&graph, &solution));
+                explored.push(stats.explored());
+            }
+            explored_differ |= explored.iter().any(|&e| e != explored[0]);
+        }
+        assert!(explored_differ);
+    }
+
+    #[test]
+    fn bb_config_min_width() {
+        assert_eq!(BBConfig::default().row_width(5), 8);
+        assert_eq!(BBConfig::default().row_width(33), 64);
+        let with_min_width = |min_width| BBConfig {
+            min_width,
+            ..Default::default()
+        };
+        assert_eq!(with_min_width(64).row_width(5), 64);
+        assert_eq!(with_min_width(20).row_width(5), 32);
+
+        // bidirected 5-cycle has a minimum DFVS of size 3
+        let graph = AdjListMatrix::from(&[
+            (0, 1),
+            (1, 0),
+            (1, 2),
+            (2, 1),
+            (2, 3),
+            (3, 2),
+            (3, 4),
+            (4, 3),
+            (4, 0),
+            (0, 4),
+        ]);
+        for min_width in [8, 16, 32, 64, 128] {
+            let solution = branch_and_bound_matrix_stats(
+                &graph,
+                None,
+                &with_min_width(min_width),
+                &mut BBStats::new(),
+            )
+            .unwrap();
+            assert_eq!(solution.len(), 3);
+            assert!(is_dfvs(&graph, &solution));
+        }
+
+        let mut gen = Pcg64Mcg::seed_from_u64(5);
+        for _ in 0..5 {
+            let graph: AdjListMatrix = generate_gnp(&mut gen, 12, 0.2);
+            let optimum = branch_and_bound_matrix(&graph, None).unwrap();
+            let solution = branch_and_bound_matrix_stats(
+                &graph,
+                None,
+                &with_min_width(64),
+                &mut BBStats::new(),
+            )
+            .unwrap();
+            assert_eq!(solution.len(), optimum.len());
+        }
+    }
+
+    #[test]
+    fn bb_stats_counters() {
+        // bidirected 5-cycle with a loop at node 2
+        let mut graph = AdjListMatrix::new(5);
+        graph.connect_cycle([0, 1, 2, 3, 4]);
+        graph.connect_cycle([4, 3, 2, 1, 0]);
+        graph.add_edge(2, 2);
+
+        let mut stats = BBStats::new();
+        let solution =
+            branch_and_bound_matrix_stats(&graph, None, &BBConfig::default(), &mut stats).unwrap();
+        assert_eq!(solution.len(), 3);
+        assert!(stats.scc_decompositions() > 0);
+        assert!(stats.ub_improvements() > 0);
+        assert!(stats.max_depth() > 0);
+        assert!(stats.pruned() > 0);
+
+        let mut merged = BBStats::new();
+        merged.merge(&stats);
+        merged.merge(&stats);
+        assert_eq!(merged.scc_decompositions(), 2 * stats.scc_decompositions());
+        assert_eq!(merged.ub_improvements(), 2 * stats.ub_improvements());
+        assert_eq!(merged.pruned(), 2 * stats.pruned());
+        assert_eq!(merged.max_depth(), stats.max_depth());
+    }
+
+    #[test]
+    fn bb_memoization() {
+        let mut gen = Pcg64Mcg::seed_from_u64(7);
+        for _ in 0..5 {
+            // several copies of the same SCC, hence the same subproblems recur
+            let part: AdjArray = generate_gnp(&mut gen, 12, 0.5);
+            let graph = AdjArray::concat([&part, &part, &part]);
+
+            let uncached = branch_and_bound_matrix_lower_stats(
+                &graph,
+                0,
+                None,
+                &BBConfig::default(),
+                &mut BBStats::new(),
+            )
+            .unwrap();
+
+            let config = BBConfig {
+                memo_entries: 1000,
+                ..Default::default()
+            };
+            let mut stats = BBStats::new();
+            let cached =
+                branch_and_bound_matrix_lower_stats(&graph, 0, None, &config, &mut stats).unwrap();
+
+            assert_eq!(cached.len(), uncached.len());
+            assert!(is_dfvs(&graph, &cached));
+            assert!(stats.memo_hits() > 0);
+        }
+
+        // the cache must not change the solution size on instances without repetitions either
+        for _ in 0..20 {
+            let graph: AdjArray = generate_gnp(&mut gen, 20, 0.2);
+            let uncached = branch_and_bound_matrix_lower_stats(
+                &graph,
+                0,
+                None,
+                &BBConfig::default(),
+                &mut BBStats::new(),
+            )
+            .unwrap();
+            let config = BBConfig {
+                memo_entries: 10,
+                ..Default::default()
+            };
+            let mut stats = BBStats::new();
+            let cached =
+                branch_and_bound_matrix_lower_stats(&graph, 0, None, &config, &mut stats).unwrap();
+            assert_eq!(cached.len(), uncached.len());
+        }
+    }
+
+    #[test]
+    fn bb_parallel_sccs() {
+        let mut gen = Pcg64Mcg::seed_from_u64(42);
+
+        // disjoint union of several hard SCCs, each on 16 to 24 nodes
+        let parts: Vec<AdjArray> = (0..5)
+            .map(|i| generate_gnp(&mut gen, 16 + 2 * i, 0.3))
+            .collect();
+        let mut graph = AdjArray::concat(parts.iter());
+        graph.add_edge(0, parts[0].len() as Node); // connect two parts acyclically
+
+        let sequential = branch_and_bound_matrix_lower_stats(
+            &graph,
+            0,
+            None,
+            &BBConfig::default(),
+            &mut BBStats::new(),
+        )
+        .unwrap();
+
+        let mut stats = BBStats::new();
+        let parallel =
+            branch_and_bound_matrix_par_stats(&graph, None, &BBConfig::default(), &mut stats)
+                .unwrap();
+        assert_eq!(parallel.len(), sequential.len());
+        assert!(stats.explored() > 0);
+
+        assert!(is_dfvs(&graph, &parallel));
+
+        let k = sequential.len() as Node;
+        assert!(branch_and_bound_matrix_par_stats(
+            &graph,
+            Some(k),
+            &BBConfig::default(),
+            &mut BBStats::new()
+        )
+        .is_some());
+        assert!(branch_and_bound_matrix_par_stats(
+            &graph,
+            Some(k - 1),
+            &BBConfig::default(),
+            &mut BBStats::new()
+        )
+        .is_none());
+    }
+
+    #[test]
+    fn bb_deadline() {
+        let mut gen = Pcg64Mcg::seed_from_u64(1234);
+
+        // a generous deadline on a small graph agrees with the classic interface
+        for n in [10, 20] {
+            let graph: AdjArray = generate_gnp(&mut gen, n, 4.0 / n as f64);
+            let deadline = Instant::now() + std::time::Duration::from_secs(3600);
+            let (solution, proven) = branch_and_bound_matrix_deadline(
+                &graph,
+                None,
+                deadline,
+                &BBConfig::default(),
+                &mut BBStats::new(),
+            );
+            assert!(proven);
+            assert_eq!(solution, branch_and_bound_matrix(&graph, None));
+        }
+
+        // an absurdly short deadline on a hard instance
+        let graph: AdjArray = generate_gnp(&mut gen, 128, 0.05);
+        let start = Instant::now();
+        let (solution, proven) = branch_and_bound_matrix_deadline(
+            &graph,
+            None,
+            start,
+            &BBConfig::default(),
+            &mut BBStats::new(),
+        );
+        assert!(start.elapsed().as_secs() < 5);
+        assert!(!proven);
+        assert!(is_dfvs(&graph, &solution.unwrap()));
+
+        let mut algo = BranchAndBoundMatrix::new(&graph);
+        algo.set_deadline(Instant::now());
+        algo.execute_step();
+        assert!(!algo.is_completed());
+        let solution = algo.best_known_solution().unwrap().to_vec();
+        assert!(is_dfvs(&graph, &solution));
+    }
+
+    #[test]
+    fn bb_cancellable() {
+        // the search on this instance takes far longer than the canceller waits
+        let mut gen = Pcg64Mcg::seed_from_u64(1234);
+        let graph: AdjArray = generate_gnp(&mut gen, 128, 0.05);
+
+        let cancel = AtomicBool::new(false);
+        let mut stats = BBStats::new();
+        let (solution, proven) = std::thread::scope(|scope| {
+            let canceller = scope.spawn(|| {
+                std::thread::sleep(std::time::Duration::from_millis(20));
+                cancel.store(true, std::sync::atomic::Ordering::Relaxed);
+            });
+            let result = branch_and_bound_matrix_cancellable(
+                &graph,
+                None,
+                &BBConfig::default(),
+                &mut stats,
+                &cancel,
+            );
+            canceller.join().unwrap();
+            result
+        });
+
+        assert!(!proven);
+        assert!(stats.aborted());
+        assert!(stats.cancelled());
+        assert!(is_dfvs(&graph, &solution.unwrap()));
+
+        // a flag set before the call stops the search at the first SCC boundary
+        static CANCEL: AtomicBool = AtomicBool::new(true);
+        let mut stats = BBStats::new();
+        let (solution, proven) = branch_and_bound_matrix_cancellable(
+            &graph,
+            None,
+            &BBConfig::default(),
+            &mut stats,
+            &CANCEL,
+        );
+        assert!(!proven);
+        assert!(stats.aborted());
+        assert!(is_dfvs(&graph, &solution.unwrap()));
+
+        // an unset flag does not change anything
+        let graph = AdjArray::from(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 2)]);
+        let (solution, proven) = branch_and_bound_matrix_cancellable(
+            &graph,
+            None,
+            &BBConfig::default(),
+            &mut BBStats::new(),
+            &AtomicBool::new(false),
+        );
+        assert!(proven);
+        assert_eq!(solution, Some(vec![2]));
+    }
+
     #[test]
     fn planted_cycles() {
         let mut gen = Pcg64Mcg::seed_from_u64(234);
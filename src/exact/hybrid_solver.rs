@@ -0,0 +1,190 @@
+use crate::algorithm::*;
+use crate::exact::branch_and_bound_matrix::bb_stats::BBStats;
+use crate::exact::branch_and_bound_matrix::{
+    branch_and_bound_matrix_deadline, branch_and_bound_matrix_warm_start, BBConfig,
+};
+use crate::graph::*;
+use crate::heuristics::local_search::iterated_local_search::IteratedLocalSearch;
+use rand::Rng;
+use std::time::{Duration, Instant};
+
+/// Anytime solver that first improves a heuristic incumbent with a fixed number of
+/// [`IteratedLocalSearch`] steps (each step of this algorithm carries out one of them) and then
+/// runs the matrix branch and bound, warm-started with the incumbent, until optimality is proven.
+///
+/// By default, the branch and bound is carried out in a single (possibly long) step. If a time
+/// slice is set via [`HybridSolver::set_exact_time_slice`], each step instead runs the branch and
+/// bound for at most this long, searching only for solutions smaller than the incumbent; the
+/// slice is doubled whenever the search did not finish. This keeps
+/// [`IterativeAlgorithm::run_until_timeout`] responsive while [`IterativeAlgorithm::best_known_solution`]
+/// always returns a valid DFVS.
+///
+/// # Example
+/// ```
+/// use dfvs::algorithm::*;
+/// use dfvs::exact::hybrid_solver::HybridSolver;
+/// use dfvs::graph::*;
+/// use rand::SeedableRng;
+///
+/// let graph = AdjArray::from(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 2)]);
+/// let mut rng = rand_pcg::Pcg64Mcg::seed_from_u64(0);
+/// let solution = HybridSolver::new(&graph, &mut rng, 10).run_to_completion().unwrap();
+/// assert_eq!(solution, vec![2]);
+/// ```
+pub struct HybridSolver<'a, G, R> {
+    graph: &'a G,
+    ils: IteratedLocalSearch<'a, R>,
+    /// Remaining steps of the heuristic phase
+    heuristic_steps: usize,
+    exact_time_slice: Option<Duration>,
+    incumbent: Vec<Node>,
+    proven: bool,
+}
+
+impl<'a, G, R> HybridSolver<'a, G, R>
+where
+    G: 'a + AdjacencyList,
+    R: Rng,
+{
+    pub fn new(graph: &'a G, rng: &'a mut R, heuristic_steps: usize) -> Self {
+        let mut ils = IteratedLocalSearch::new(graph, rng, 3);
+        let incumbent = ils.best_known_solution().unwrap().to_vec();
+        Self {
+            graph,
+            ils,
+            heuristic_steps,
+            exact_time_slice: None,
+            incumbent,
+            proven: false,
+        }
+    }
+
+    /// Limits the time spent in the branch and bound per step (see type-level documentation)
+    pub fn set_exact_time_slice(&mut self, time_slice: Duration) {
+        self.exact_time_slice = Some(time_slice);
+    }
+
+    /// Returns true iff the heuristic phase is finished
+    pub fn in_exact_phase(&self) -> bool {
+        self.heuristic_steps == 0 || self.ils.is_completed()
+    }
+
+    fn execute_exact_step(&mut self) {
+        if self.incumbent.is_empty() {
+            self.proven = true;
+            return;
+        }
+
+        match self.exact_time_slice {
+            None => {
+                self.incumbent = branch_and_bound_matrix_warm_start(
+                    self.graph,
+                    &self.incumbent,
+                    &BBConfig::default(),
+                    &mut BBStats::new(),
+                );
+                self.proven = true;
+            }
+
+            Some(time_slice) => {
+                let (solution, proven) = branch_and_bound_matrix_deadline(
+                    self.graph,
+                    Some(self.incumbent.len() as Node - 1),
+                    Instant::now() + time_slice,
+                    &BBConfig::default(),
+                    &mut BBStats::new(),
+                );
+
+                if let Some(solution) = solution {
+                    self.incumbent = solution;
+                }
+
+                self.proven = proven;
+                if !proven {
+                    self.exact_time_slice = Some(2 * time_slice);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, G, R> IterativeAlgorithm for HybridSolver<'a, G, R>
+where
+    G: 'a + AdjacencyList,
+    R: Rng,
+{
+    fn execute_step(&mut self) {
+        if self.in_exact_phase() {
+            self.execute_exact_step();
+            return;
+        }
+
+        self.ils.execute_step();
+        self.heuristic_steps -= 1;
+
+        let heuristic = self.ils.best_known_solution().unwrap();
+        if heuristic.len() < self.incumbent.len() {
+            self.incumbent = heuristic.to_vec();
+        }
+    }
+
+    fn is_completed(&self) -> bool {
+        self.proven
+    }
+
+    fn best_known_solution(&mut self) -> Option<&[Node]> {
+        Some(&self.incumbent)
+    }
+}
+
+impl<'a, G, R> TerminatingIterativeAlgorithm for HybridSolver<'a, G, R>
+where
+    G: 'a + AdjacencyList,
+    R: Rng,
+{
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exact::branch_and_bound_matrix::branch_and_bound_matrix;
+    use crate::heuristics::utils::apply_fvs_to_graph;
+    use crate::random_models::gnp::generate_gnp;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
+
+    fn drive_to_optimality(graph: &AdjArrayIn, time_slice: Option<Duration>) {
+        let optimum = branch_and_bound_matrix(graph, None).unwrap();
+
+        let mut rng = Pcg64Mcg::seed_from_u64(1);
+        let mut solver = HybridSolver::new(graph, &mut rng, 5);
+        if let Some(time_slice) = time_slice {
+            solver.set_exact_time_slice(time_slice);
+        }
+
+        let mut prev_size = graph.len() + 1;
+        while !solver.is_completed() {
+            let incumbent = solver.best_known_solution().unwrap().to_vec();
+            assert!(incumbent.len() <= prev_size);
+            assert!(apply_fvs_to_graph(graph, incumbent.iter().copied()).is_acyclic());
+            prev_size = incumbent.len();
+
+            solver.execute_step();
+        }
+
+        assert!(solver.in_exact_phase());
+        let solution = solver.best_known_solution().unwrap().to_vec();
+        assert_eq!(solution.len(), optimum.len());
+        assert!(apply_fvs_to_graph(graph, solution).is_acyclic());
+    }
+
+    #[test]
+    fn step_by_step() {
+        let mut gen_rng = Pcg64Mcg::seed_from_u64(3);
+        for n in [1, 5, 20, 40] {
+            let graph: AdjArrayIn = generate_gnp(&mut gen_rng, n, 0.1);
+            drive_to_optimality(&graph, None);
+            drive_to_optimality(&graph, Some(Duration::from_micros(10)));
+        }
+    }
+}
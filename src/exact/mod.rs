@@ -1,6 +1,9 @@
+pub mod all_minimum_dfvs;
 pub mod branch_and_bound;
 pub mod branch_and_bound_matrix;
 pub mod branch_and_bound_on_graphs;
 pub mod exhaustive_search;
+pub mod hybrid_solver;
+pub mod vertex_cover;
 
 pub use branch_and_bound::BranchAndBound;
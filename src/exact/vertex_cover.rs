@@ -0,0 +1,226 @@
+use crate::bitset::BitSet;
+use crate::exact::branch_and_bound_matrix::branch_and_bound_matrix;
+use crate::graph::*;
+
+/// Returns true iff for every edge (u, v) the reverse edge (v, u) exists, i.e. the graph is the
+/// bidirected version of an undirected graph (self-loops are allowed). For such graphs, the
+/// minimum DFVS coincide with the minimum vertex covers of the underlying undirected graph.
+pub fn is_bidirected<G: AdjacencyList + AdjacencyTest>(graph: &G) -> bool {
+    graph.edges().all(|(u, v)| graph.has_edge(v, u))
+}
+
+/// Computes a minimum vertex cover of the undirected graph underlying `graph`, i.e. edges are
+/// treated as undirected and a node with a self-loop has to be in the cover. The result is
+/// sorted and is a minimum DFVS if `graph` is bidirected (see [`is_bidirected`]).
+///
+/// The branching follows the classic scheme: nodes of degree 1 are resolved by taking their
+/// neighbor; otherwise a node v of maximum degree is either taken or all of its neighbors are.
+/// Branches are pruned with the bound |E| / max degree.
+pub fn minimum_vertex_cover<G: AdjacencyList>(graph: &G) -> Vec<Node> {
+    let n = graph.len();
+    let mut neighbors = vec![BitSet::new(n); n];
+    let mut chosen = Vec::new();
+    for (u, v) in graph.edges() {
+        if u == v {
+            chosen.push(u);
+        } else {
+            neighbors[u as usize].set_bit(v as usize);
+            neighbors[v as usize].set_bit(u as usize);
+        }
+    }
+    chosen.sort_unstable();
+    chosen.dedup();
+
+    let alive = BitSet::new_all_set_but(n, chosen.iter().copied());
+    let mut solver = VertexCoverSolver {
+        neighbors,
+        best: alive.iter().map(|u| u as Node).collect(),
+    };
+    solver.branch(alive, Vec::new());
+
+    chosen.extend(&solver.best);
+    chosen.sort_unstable();
+    chosen
+}
+
+struct VertexCoverSolver {
+    neighbors: Vec<BitSet>,
+    /// Smallest cover of the nodes without self-loops found so far
+    best: Vec<Node>,
+}
+
+impl VertexCoverSolver {
+    fn branch(&mut self, mut alive: BitSet, mut chosen: Vec<Node>) {
+        let (max_node, max_degree) = loop {
+            if chosen.len() >= self.best.len() {
+                return;
+            }
+
+            let mut max_node = 0;
+            let mut max_degree = 0;
+            let mut sum_of_degrees = 0;
+            let mut pendant = None;
+            for u in alive.iter() {
+                let degree = self.neighbors[u].intersection_cardinality(&alive);
+                sum_of_degrees += degree;
+                if degree == 1 && pendant.is_none() {
+                    pendant = Some(u);
+                }
+                if degree > max_degree {
+                    max_node = u;
+                    max_degree = degree;
+                }
+            }
+
+            if max_degree == 0 {
+                self.best = chosen;
+                return;
+            }
+
+            let num_edges = sum_of_degrees / 2;
+            if chosen.len() + num_edges.div_ceil(max_degree) >= self.best.len() {
+                return;
+            }
+
+            match pendant {
+                Some(u) => {
+                    // some minimum cover contains the only neighbor of u instead of u
+                    let v = self.neighbors[u].iter().find(|&v| alive[v]).unwrap();
+                    alive.unset_bit(v);
+                    chosen.push(v as Node);
+                }
+                None => break (max_node, max_degree),
+            }
+        };
+
+        // branch 1: take all neighbors of max_node
+        {
+            let mut alive = alive.clone();
+            let mut chosen = chosen.clone();
+            chosen.reserve(max_degree);
+            for v in self.neighbors[max_node].iter() {
+                if alive.unset_bit(v) {
+                    chosen.push(v as Node);
+                }
+            }
+            alive.unset_bit(max_node);
+            self.branch(alive, chosen);
+        }
+
+        // branch 2: take max_node itself
+        alive.unset_bit(max_node);
+        chosen.push(max_node as Node);
+        self.branch(alive, chosen);
+    }
+}
+
+/// Computes a minimum DFVS by solving each non-trivial SCC independently: SCCs in which every
+/// edge is bidirected are solved via [`minimum_vertex_cover`], all others via
+/// [`branch_and_bound_matrix`]. Returns the sorted solution.
+/// ** Panics if an SCC that is not bidirected has more than 128 nodes **
+pub fn branch_and_bound_vertex_cover_aware<G: AdjacencyList>(graph: &G) -> Vec<Node> {
+    let mut local_id = vec![Node::MAX; graph.len()];
+    let mut solution = Vec::new();
+
+    for scc in graph.strongly_connected_components_no_singletons() {
+        for (i, &u) in scc.iter().enumerate() {
+            local_id[u as usize] = i as Node;
+        }
+
+        let mut subgraph = AdjListMatrix::new(scc.len());
+        for &u in &scc {
+            for v in graph.out_neighbors(u) {
+                if local_id[v as usize] != Node::MAX {
+                    subgraph.add_edge(local_id[u as usize], local_id[v as usize]);
+                }
+            }
+        }
+
+        let scc_solution = if is_bidirected(&subgraph) {
+            minimum_vertex_cover(&subgraph)
+        } else {
+            branch_and_bound_matrix(&subgraph, None).unwrap()
+        };
+        solution.extend(scc_solution.into_iter().map(|u| scc[u as usize]));
+
+        for &u in &scc {
+            local_id[u as usize] = Node::MAX;
+        }
+    }
+
+    solution.sort_unstable();
+    solution
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random_models::gnp::generate_gnp;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
+
+    fn bidirect(graph: &AdjListMatrix) -> AdjListMatrix {
+        let mut result = graph.clone();
+        for (u, v) in graph.edges_vec() {
+            result.try_add_edge(v, u);
+        }
+        result
+    }
+
+    #[test]
+    fn bidirected_triangle() {
+        let graph = AdjListMatrix::from(&[(0, 1), (1, 0), (1, 2), (2, 1), (2, 0), (0, 2)]);
+        assert!(is_bidirected(&graph));
+
+        let cover = minimum_vertex_cover(&graph);
+        assert_eq!(cover.len(), 2);
+        assert!(is_dfvs(&graph, &cover));
+        assert_eq!(branch_and_bound_vertex_cover_aware(&graph), cover);
+        assert_eq!(branch_and_bound_matrix(&graph, None).unwrap().len(), 2);
+
+        let mut directed = graph;
+        directed.remove_edge(0, 2);
+        assert!(!is_bidirected(&directed));
+    }
+
+    #[test]
+    fn self_loops() {
+        let graph = AdjListMatrix::from(&[(0, 0), (0, 1), (1, 0), (1, 2), (2, 1), (3, 3)]);
+        assert!(is_bidirected(&graph));
+        let cover = minimum_vertex_cover(&graph);
+        assert_eq!(cover.len(), 3);
+        assert!(cover.contains(&0) && cover.contains(&3));
+        assert!(is_dfvs(&graph, &cover));
+    }
+
+    #[test]
+    fn matches_general_solver() {
+        let mut rng = Pcg64Mcg::seed_from_u64(6);
+        for n in [10, 20, 40, 60] {
+            for _ in 0..5 {
+                let graph: AdjListMatrix = generate_gnp(&mut rng, n, 2.0 / n as f64);
+                let undirected = bidirect(&graph);
+                let optimum = branch_and_bound_matrix(&undirected, None).unwrap();
+
+                let cover = minimum_vertex_cover(&undirected);
+                assert_eq!(cover.len(), optimum.len());
+                assert!(is_dfvs(&undirected, &cover));
+
+                // mixed instance: a directed SCC next to the bidirected one
+                let mut mixed = AdjListMatrix::new(2 * n as usize);
+                for (u, v) in undirected.edges_iter() {
+                    mixed.add_edge(u, v);
+                }
+                for (u, v) in graph.edges_iter() {
+                    mixed.add_edge(u + n, v + n);
+                }
+                let solution = branch_and_bound_vertex_cover_aware(&mixed);
+                assert!(is_dfvs(&mixed, &solution));
+                assert_eq!(
+                    solution.len(),
+                    branch_and_bound_matrix(&mixed, None).unwrap().len()
+                );
+            }
+        }
+    }
+}
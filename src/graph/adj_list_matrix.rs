@@ -146,11 +146,111 @@ impl AdjacencyListIn for AdjListMatrixIn {
     }
 }
 
+impl From<&AdjArray> for AdjListMatrix {
+    /// Keeps the number of nodes; multi-edges are merged as AdjListMatrix does not support them
+    fn from(graph: &AdjArray) -> Self {
+        let mut result = AdjListMatrix::new(graph.len());
+        for (u, v) in graph.edges_iter() {
+            result.try_add_edge(u, v);
+        }
+        result
+    }
+}
+
+impl From<&AdjListMatrix> for AdjArray {
+    fn from(graph: &AdjListMatrix) -> Self {
+        graph.adj_array.clone()
+    }
+}
+
+#[cfg(feature = "petgraph")]
+mod petgraph_impl {
+    use super::*;
+    use petgraph::graph::{DiGraph, NodeIndex};
+    use petgraph::visit::EdgeRef;
+
+    impl AdjListMatrix {
+        /// Copies a petgraph graph keeping the node indices; parallel edges are merged as
+        /// AdjListMatrix has no multi-edges. `From` cannot be used here as it would conflict with
+        /// the implementation for edge iterators.
+        pub fn from_petgraph(graph: &DiGraph<(), ()>) -> Self {
+            let mut result = AdjListMatrix::new(graph.node_count());
+            for edge in graph.edge_references() {
+                result.try_add_edge(edge.source().index() as Node, edge.target().index() as Node);
+            }
+            result
+        }
+    }
+
+    impl From<&AdjListMatrix> for DiGraph<(), ()> {
+        fn from(graph: &AdjListMatrix) -> Self {
+            let mut result = DiGraph::with_capacity(graph.len(), graph.number_of_edges());
+            for _ in graph.vertices() {
+                result.add_node(());
+            }
+            for (u, v) in graph.edges_iter() {
+                result.add_edge(NodeIndex::new(u as usize), NodeIndex::new(v as usize), ());
+            }
+            result
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn round_trip() {
+            let mut digraph = DiGraph::<(), ()>::new();
+            let nodes: Vec<_> = (0..5).map(|_| digraph.add_node(())).collect();
+            for &(u, v) in &[(0, 1), (1, 2), (2, 0), (2, 2), (3, 1)] {
+                digraph.add_edge(nodes[u], nodes[v], ());
+            }
+
+            let graph = AdjListMatrix::from_petgraph(&digraph);
+            assert_eq!(graph.len(), 5);
+            assert_eq!(
+                graph.edges_vec(),
+                vec![(0, 1), (1, 2), (2, 0), (2, 2), (3, 1)]
+            );
+
+            let back = DiGraph::<(), ()>::from(&graph);
+            assert_eq!(back.node_count(), 5);
+            let mut edges: Vec<_> = back
+                .edge_references()
+                .map(|e| (e.source().index() as Node, e.target().index() as Node))
+                .collect();
+            edges.sort_unstable();
+            assert_eq!(edges, graph.edges_vec());
+        }
+    }
+}
+
 #[cfg(test)]
 pub mod tests_adj_list_matrix {
     use super::graph_macros::base_tests;
     use super::*;
     base_tests!(AdjListMatrix);
+
+    #[test]
+    fn adj_array_round_trip() {
+        let mut array = AdjArray::new(7);
+        for &(u, v) in &[(0, 1), (1, 2), (2, 0), (2, 2), (3, 1), (3, 1)] {
+            array.add_edge(u, v);
+        }
+
+        let matrix = AdjListMatrix::from(&array);
+        assert_eq!(matrix.len(), 7);
+        assert_eq!(
+            matrix.edges_vec(),
+            vec![(0, 1), (1, 2), (2, 0), (2, 2), (3, 1)]
+        );
+
+        let back = AdjArray::from(&matrix);
+        assert_eq!(back.len(), 7);
+        assert_eq!(back.edges_vec(), matrix.edges_vec());
+        assert_eq!(AdjListMatrix::from(&back).edges_vec(), matrix.edges_vec());
+    }
 }
 
 #[cfg(test)]
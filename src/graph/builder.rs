@@ -0,0 +1,115 @@
+use super::*;
+
+/// Accumulates edges from possibly noisy sources (e.g. containing duplicates) and builds an
+/// [`AdjListMatrix`] without multi-edges whose adjacency lists are sorted increasingly.
+///
+/// # Example
+/// ```
+/// use dfvs::graph::*;
+/// let graph = GraphBuilder::new()
+///     .add_edges([(2, 0), (0, 1), (2, 0), (1, 1)])
+///     .drop_self_loops(true)
+///     .num_nodes(5)
+///     .build();
+/// assert_eq!(graph.len(), 5);
+/// assert_eq!(graph.edges_vec(), vec![(0, 1), (2, 0)]);
+/// ```
+#[derive(Clone, Debug, Default)]
+pub struct GraphBuilder {
+    num_nodes: usize,
+    drop_self_loops: bool,
+    edges: Vec<Edge>,
+}
+
+impl GraphBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// The graph has at least `n` nodes; by default the number of nodes is inferred from the
+    /// largest node id
+    pub fn num_nodes(&mut self, n: usize) -> &mut Self {
+        self.num_nodes = n;
+        self
+    }
+
+    /// If set, edges (u, u) are ignored. Default: false
+    pub fn drop_self_loops(&mut self, value: bool) -> &mut Self {
+        self.drop_self_loops = value;
+        self
+    }
+
+    pub fn add_edge(&mut self, u: Node, v: Node) -> &mut Self {
+        self.edges.push((u, v));
+        self
+    }
+
+    pub fn add_edges(&mut self, edges: impl IntoIterator<Item = Edge>) -> &mut Self {
+        self.edges.extend(edges);
+        self
+    }
+
+    /// Number of edges added so far (including duplicates and self-loops)
+    pub fn number_of_added_edges(&self) -> usize {
+        self.edges.len()
+    }
+
+    pub fn build(&self) -> AdjListMatrix {
+        let mut edges = self.edges.clone();
+        if self.drop_self_loops {
+            edges.retain(|&(u, v)| u != v);
+        }
+        edges.sort_unstable();
+        edges.dedup();
+
+        let n = self
+            .edges
+            .iter()
+            .map(|&(u, v)| u.max(v) as usize + 1)
+            .max()
+            .unwrap_or(0);
+        AdjListMatrix::from_edges(n.max(self.num_nodes), edges)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use itertools::Itertools;
+
+    #[test]
+    fn dedup_edges() {
+        let mut builder = GraphBuilder::new();
+        builder
+            .add_edges([(3, 1), (0, 1), (1, 0), (3, 1), (0, 2), (1, 0)])
+            .add_edge(2, 2)
+            .add_edge(0, 1);
+        assert_eq!(builder.number_of_added_edges(), 8);
+
+        let graph = builder.build();
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.number_of_edges(), 5);
+        assert_eq!(
+            graph.edges_vec(),
+            vec![(0, 1), (0, 2), (1, 0), (2, 2), (3, 1)]
+        );
+        assert_eq!(graph.out_neighbors(0).collect_vec(), vec![1, 2]);
+
+        let graph = builder.drop_self_loops(true).build();
+        assert_eq!(graph.len(), 4);
+        assert_eq!(graph.edges_vec(), vec![(0, 1), (0, 2), (1, 0), (3, 1)]);
+    }
+
+    #[test]
+    fn isolated_nodes() {
+        assert!(GraphBuilder::new().build().is_empty());
+
+        let graph = GraphBuilder::new().num_nodes(10).add_edge(1, 2).build();
+        assert_eq!(graph.len(), 10);
+        assert_eq!(graph.edges_vec(), vec![(1, 2)]);
+
+        // the number of nodes never drops below the largest id
+        let graph = GraphBuilder::new().num_nodes(1).add_edge(4, 4).build();
+        assert_eq!(graph.len(), 5);
+    }
+}
@@ -24,6 +24,29 @@ pub trait Connectivity: AdjacencyList + Traversal + Sized {
         sc.collect_vec()
     }
 
+    /// Returns the strongly connected components (including singletons) in a deterministic
+    /// order: the nodes of each SCC are sorted increasingly and the SCCs by their smallest node
+    /// (see [`sort_sccs`]).
+    ///
+    /// # Example
+    /// ```
+    /// use dfvs::graph::*;
+    /// let graph = AdjListMatrix::from(&[(4, 5), (5, 4), (2, 2), (1, 0), (0, 1)]);
+    /// let sccs = graph.strongly_connected_components_sorted();
+    /// assert_eq!(sccs, vec![vec![0, 1], vec![2], vec![3], vec![4, 5]]);
+    /// ```
+    fn strongly_connected_components_sorted(&self) -> Vec<Vec<Node>> {
+        sort_sccs(self.strongly_connected_components())
+    }
+
+    /// Returns a lazy iterator over the strongly connected components (including singletons).
+    /// Components are computed one at a time as Tarjan's algorithm finds them, so in contrast to
+    /// [`Connectivity::strongly_connected_components`] they never have to be stored all at once.
+    /// Use [`StronglyConnected::set_include_singletons`] to skip trivial components.
+    fn scc_iter(&self) -> StronglyConnected<'_, Self> {
+        StronglyConnected::new(self)
+    }
+
     /// Returns the strongly connected components of the graph as a Vec<Vec<Node>>
     /// In contrast to [`Connectivity::strongly_connected_components`], this methods includes SCCs of size 1
     /// if and only if the node has a self-loop
@@ -69,6 +92,111 @@ pub trait Connectivity: AdjacencyList + Traversal + Sized {
         }
         partition
     }
+
+    /// Returns the nodes that lie on at least one directed cycle, i.e. the members of non-trivial
+    /// SCCs and the nodes with self-loops. Only these nodes can be part of a minimum DFVS.
+    ///
+    /// # Example
+    /// ```
+    /// use dfvs::graph::*;
+    /// let graph = AdjListMatrix::from(&[(0, 1), (1, 0), (1, 2), (3, 3)]);
+    /// assert_eq!(graph.cyclic_nodes().iter().collect::<Vec<_>>(), [0, 1, 3]);
+    /// ```
+    fn cyclic_nodes(&self) -> BitSet {
+        let mut cyclic = BitSet::new(self.len());
+        let mut sc = StronglyConnected::new(self);
+        sc.set_include_singletons(false);
+        for scc in sc {
+            for u in scc {
+                cyclic.set_bit(u as usize);
+            }
+        }
+        cyclic
+    }
+
+    /// Returns the condensation of the graph, i.e. the DAG containing one node per SCC (including
+    /// trivial ones) and an edge between two SCCs iff there is an edge between their members.
+    /// The second component maps each node of the condensation to the nodes of its SCC.
+    fn condensation(&self) -> (AdjListMatrix, Vec<Vec<Node>>) {
+        let sccs = self.strongly_connected_components();
+
+        let mut scc_of_node = vec![Node::MAX; self.len()];
+        for (i, scc) in sccs.iter().enumerate() {
+            for &u in scc {
+                scc_of_node[u as usize] = i as Node;
+            }
+        }
+
+        let mut condensed = AdjListMatrix::new(sccs.len());
+        for (u, v) in self.edges_iter() {
+            let (su, sv) = (scc_of_node[u as usize], scc_of_node[v as usize]);
+            if su != sv {
+                condensed.try_add_edge(su, sv);
+            }
+        }
+
+        (condensed, sccs)
+    }
+
+    /// Returns true iff all nodes belong to a single SCC, i.e. every node can reach every other one.
+    /// Instead of computing all SCCs, this checks whether an arbitrary node reaches all nodes in
+    /// the graph and its transpose. A graph with a single node is strongly connected (independent
+    /// of self-loops), while the empty graph is not as it has no SCC at all.
+    fn is_strongly_connected(&self) -> bool {
+        if self.is_empty() {
+            return false;
+        }
+
+        self.dfs(0).count() == self.len()
+            && self.transpose_as::<AdjArray>().dfs(0).count() == self.len()
+    }
+
+    /// Returns the weakly connected components, i.e. the connected components if edge directions
+    /// are ignored. Isolated nodes form singleton components. Components are ordered by their
+    /// smallest node and the nodes within each component are sorted.
+    ///
+    /// # Example
+    /// ```
+    /// use dfvs::graph::*;
+    /// let graph = AdjListMatrix::from(&[(0, 1), (2, 1), (3, 4)]);
+    /// assert_eq!(graph.weakly_connected_components(), vec![vec![0, 1, 2], vec![3, 4]]);
+    /// ```
+    fn weakly_connected_components(&self) -> Vec<Vec<Node>> {
+        // union-find with path halving and union by size
+        fn find(parent: &mut [Node], mut u: Node) -> Node {
+            while parent[u as usize] != u {
+                parent[u as usize] = parent[parent[u as usize] as usize];
+                u = parent[u as usize];
+            }
+            u
+        }
+
+        let mut parent: Vec<Node> = self.vertices_range().collect();
+        let mut size = vec![1usize; self.len()];
+        for (u, v) in self.edges() {
+            let (mut ru, mut rv) = (find(&mut parent, u), find(&mut parent, v));
+            if ru == rv {
+                continue;
+            }
+            if size[ru as usize] < size[rv as usize] {
+                std::mem::swap(&mut ru, &mut rv);
+            }
+            parent[rv as usize] = ru;
+            size[ru as usize] += size[rv as usize];
+        }
+
+        let mut component_of_root = vec![usize::MAX; self.len()];
+        let mut components: Vec<Vec<Node>> = Vec::new();
+        for u in self.vertices_range() {
+            let root = find(&mut parent, u) as usize;
+            if component_of_root[root] == usize::MAX {
+                component_of_root[root] = components.len();
+                components.push(Vec::new());
+            }
+            components[component_of_root[root]].push(u);
+        }
+        components
+    }
 }
 
 impl<T: AdjacencyList + Sized> Connectivity for T {}
@@ -134,6 +262,9 @@ impl<'a, T: AdjacencyList> StronglyConnected<'a, T> {
     /// Put a pristine stack frame on the call stack. Roughly speaking, this is the first step
     /// to a recursive call of search.
     fn push_node(&mut self, node: Node, parent: Option<Node>) {
+        // each node is pushed at most once, so a deeper call stack indicates broken bookkeeping
+        debug_assert!(self.call_stack.len() < self.graph.len());
+        debug_assert!(!self.states[node as usize].visited);
         self.call_stack.push(StackFrame {
             node,
             parent: parent.unwrap_or(node),
@@ -225,6 +356,8 @@ impl<'a, T: AdjacencyList> StronglyConnected<'a, T> {
             }
         }
 
+        // all nodes reached from the start node were assigned to an SCC (or skipped as singleton)
+        debug_assert!(self.path_stack.is_empty());
         None
     }
 }
@@ -312,7 +445,11 @@ impl<'a, T: AdjacencyList> Iterator for StronglyConnected<'a, T> {
                 return Some(x);
             }
 
-            self.next_unvisited_node()?;
+            if self.next_unvisited_node().is_none() {
+                debug_assert_eq!(self.idx as usize, self.graph.len());
+                debug_assert!(self.states.iter().all(|s| s.visited && !s.on_stack));
+                return None;
+            }
         }
     }
 }
@@ -457,6 +594,162 @@ pub mod tests {
         assert_eq!(sccs[2], [5, 6]);
     }
 
+    #[test]
+    fn condensation() {
+        let graph = AdjListMatrix::from(&[
+            (0, 1),
+            (1, 2),
+            (1, 4),
+            (1, 5),
+            (2, 6),
+            (2, 3),
+            (3, 2),
+            (3, 7),
+            (4, 0),
+            (4, 5),
+            (5, 6),
+            (6, 5),
+            (7, 3),
+            (7, 6),
+        ]);
+
+        let (condensed, sccs) = graph.condensation();
+        assert_eq!(condensed.number_of_nodes(), 3);
+        assert_eq!(sccs.len(), 3);
+        assert!(condensed.is_acyclic());
+        assert!(!condensed.has_self_loop());
+        // {0,1,4} -> {2,3,7}, {0,1,4} -> {5,6}, {2,3,7} -> {5,6}
+        assert_eq!(condensed.number_of_edges(), 3);
+
+        let scc_of = |u: Node| sccs.iter().position(|scc| scc.contains(&u)).unwrap() as Node;
+        assert!(condensed.has_edge(scc_of(0), scc_of(2)));
+        assert!(condensed.has_edge(scc_of(0), scc_of(5)));
+        assert!(condensed.has_edge(scc_of(2), scc_of(5)));
+
+        assert_eq!(
+            sort_sccs(sccs),
+            vec![vec![0, 1, 4], vec![2, 3, 7], vec![5, 6]]
+        );
+    }
+
+    #[test]
+    fn scc_sorted() {
+        let graph = AdjListMatrix::from(&[
+            (0, 1),
+            (1, 2),
+            (1, 4),
+            (1, 5),
+            (2, 6),
+            (2, 3),
+            (3, 2),
+            (3, 7),
+            (4, 0),
+            (4, 5),
+            (5, 6),
+            (6, 5),
+            (7, 3),
+            (7, 6),
+        ]);
+
+        assert_eq!(
+            graph.strongly_connected_components_sorted(),
+            vec![vec![0, 1, 4], vec![2, 3, 7], vec![5, 6]]
+        );
+
+        let mut rng = Pcg64::seed_from_u64(4);
+        for _ in 0..10 {
+            let graph: AdjListMatrix = generate_gnp(&mut rng, 50, 0.03);
+            let sccs = graph.strongly_connected_components_sorted();
+            assert!(sccs.iter().all(|scc| scc.windows(2).all(|w| w[0] < w[1])));
+            assert!(sccs.windows(2).all(|w| w[0][0] < w[1][0]));
+            assert_eq!(sccs.iter().map(|scc| scc.len()).sum::<usize>(), 50);
+        }
+    }
+
+    #[test]
+    fn scc_iter() {
+        let mut gen = Pcg64::seed_from_u64(7);
+        for p in [0.005, 0.02, 0.05] {
+            let graph: AdjListMatrix = generate_gnp(&mut gen, 200, p);
+            let streamed = sort_sccs(graph.scc_iter().collect());
+            assert_eq!(streamed, sort_sccs(graph.strongly_connected_components()));
+
+            let mut iter = graph.scc_iter();
+            iter.set_include_singletons(false);
+            assert_eq!(
+                sort_sccs(iter.collect()),
+                sort_sccs(graph.strongly_connected_components_no_singletons())
+            );
+        }
+    }
+
+    #[test]
+    fn cyclic_nodes() {
+        // 2-cycle (2, 3) with an in-tree 0 -> 1 -> 2 and an out-tree 3 -> 4, 3 -> 5 -> 6
+        let graph = AdjArray::from(&[(0, 1), (1, 2), (2, 3), (3, 2), (3, 4), (3, 5), (5, 6)]);
+        assert_eq!(graph.cyclic_nodes().iter().collect_vec(), [2, 3]);
+
+        let mut graph = graph;
+        graph.add_edge(6, 6);
+        graph.add_edge(4, 0);
+        assert_eq!(
+            graph.cyclic_nodes().iter().collect_vec(),
+            [0, 1, 2, 3, 4, 6]
+        );
+
+        let mut path = AdjArray::new(10);
+        path.connect_path(0..10);
+        assert!(path.cyclic_nodes().empty());
+    }
+
+    #[test]
+    fn is_strongly_connected() {
+        let mut cycle = AdjArray::new(10);
+        cycle.connect_cycle(0..10);
+        assert!(cycle.is_strongly_connected());
+
+        let mut path = AdjArray::new(10);
+        path.connect_path(0..10);
+        assert!(!path.is_strongly_connected());
+
+        // node 10 is first isolated, then a sink, and finally part of the cycle
+        let mut graph = AdjArray::new(11);
+        graph.connect_cycle(0..10);
+        assert!(!graph.is_strongly_connected());
+        graph.add_edge(3, 10);
+        assert!(!graph.is_strongly_connected());
+        graph.add_edge(10, 7);
+        assert!(graph.is_strongly_connected());
+
+        assert!(!AdjArray::new(0).is_strongly_connected());
+        assert!(AdjArray::new(1).is_strongly_connected());
+        assert!(!AdjArray::new(2).is_strongly_connected());
+    }
+
+    #[test]
+    fn weakly_connected_components() {
+        // two disjoint cycles and an isolated node
+        let graph = AdjListMatrix::from(&[(0, 1), (1, 2), (2, 0), (5, 3), (3, 4), (4, 5), (6, 6)]);
+        let mut graph_with_isolated = AdjListMatrix::new(8);
+        graph_with_isolated.add_edges(&graph.edges_vec());
+
+        assert_eq!(
+            graph.weakly_connected_components(),
+            vec![vec![0, 1, 2], vec![3, 4, 5], vec![6]]
+        );
+        assert_eq!(
+            graph_with_isolated.weakly_connected_components(),
+            vec![vec![0, 1, 2], vec![3, 4, 5], vec![6], vec![7]]
+        );
+
+        // a single edge against the direction still merges the cycles
+        graph_with_isolated.add_edge(4, 1);
+        assert_eq!(graph_with_isolated.weakly_connected_components().len(), 3);
+        assert!(AdjListMatrix::new(0)
+            .weakly_connected_components()
+            .is_empty());
+    }
+
     #[test]
     pub fn scc_singletons() {
         // {0,1} and {4,5} are scc pairs, 2 is a loop, 3 is a singleton
@@ -521,6 +814,38 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn scc_self_referential() {
+        // every node has a self-loop and an edge to every node (including itself), so each
+        // neighbor iteration revisits nodes that are on the stack
+        let n = 30;
+        let mut graph = AdjArray::new(n);
+        for u in 0..n as Node {
+            for v in 0..n as Node {
+                graph.add_edge(u, v);
+            }
+        }
+        for include_singletons in [false, true] {
+            let mut sc = StronglyConnected::new(&graph);
+            sc.set_include_singletons(include_singletons);
+            let sccs = sc.collect_vec();
+            assert_eq!(sccs.len(), 1);
+            assert_eq!(sccs[0].len(), n);
+        }
+
+        // a long path of self-loops, each of which is its own non-trivial SCC
+        let n: Node = 100_000;
+        let mut graph = AdjArray::new(n as usize);
+        graph.connect_path(0..n);
+        for u in 0..n {
+            graph.add_edge(u, u);
+        }
+        let sccs = graph.strongly_connected_components_no_singletons();
+        assert_eq!(sccs.len(), n as usize);
+        assert!(sccs.iter().all(|scc| scc.len() == 1));
+        assert_eq!(graph.cyclic_nodes().cardinality(), n as usize);
+    }
+
     #[test]
     fn scc_long_cycle() {
         // assert that we can deal with very deep stacks
@@ -532,6 +857,25 @@ pub mod tests {
         assert_eq!(sccs.first().unwrap().len(), n as usize);
     }
 
+    #[test]
+    fn scc_very_long_path_and_cycle() {
+        // the implementation does not recurse, so even 200k nodes deep DFS trees are fine
+        let n: Node = 200_000;
+
+        let mut path = AdjArray::new(n as usize);
+        path.connect_path(0..n);
+        assert_eq!(path.strongly_connected_components().len(), n as usize);
+        assert!(path
+            .strongly_connected_components_no_singletons()
+            .is_empty());
+
+        let mut cycle = AdjArray::new(n as usize);
+        cycle.connect_cycle(0..n);
+        let sccs = cycle.strongly_connected_components();
+        assert_eq!(sccs.len(), 1);
+        assert_eq!(sccs[0].len(), n as usize);
+    }
+
     #[bench]
     fn bench_tarjan_sparse(b: &mut test::Bencher) {
         let mut gen = Pcg64::seed_from_u64(1234);
@@ -0,0 +1,205 @@
+use super::*;
+use itertools::Itertools;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Edit {
+    Added(Edge),
+    Removed(Edge),
+}
+
+/// Wraps a mutable graph and records all edge insertions and removals carried out through it,
+/// so that they can be undone in reverse order. This allows, e.g., a search to delete a node,
+/// recurse and restore the graph afterwards without cloning it.
+///
+/// # Example
+/// ```
+/// use dfvs::graph::*;
+/// use dfvs::graph::edit_log::EditLog;
+///
+/// let mut graph = AdjArrayIn::from(&[(0, 1), (1, 2), (2, 0)]);
+/// let mut log = EditLog::new(&mut graph);
+/// log.checkpoint();
+/// log.remove_edges_at_node(1);
+/// assert!(log.graph().is_acyclic());
+/// log.rollback();
+/// assert_eq!(graph.number_of_edges(), 3);
+/// ```
+pub struct EditLog<'a, G> {
+    graph: &'a mut G,
+    edits: Vec<Edit>,
+    /// Lengths of `edits` at the time of each active checkpoint
+    checkpoints: Vec<usize>,
+}
+
+impl<'a, G: GraphEdgeEditing + AdjacencyListIn> EditLog<'a, G> {
+    pub fn new(graph: &'a mut G) -> Self {
+        Self {
+            graph,
+            edits: Vec::new(),
+            checkpoints: Vec::new(),
+        }
+    }
+
+    /// Read-only access to the current state of the wrapped graph
+    pub fn graph(&self) -> &G {
+        self.graph
+    }
+
+    /// Marks the current state such that the next call to [`EditLog::rollback`] restores it.
+    /// Checkpoints nest; returns the number of active checkpoints including the new one.
+    pub fn checkpoint(&mut self) -> usize {
+        self.checkpoints.push(self.edits.len());
+        self.checkpoints.len()
+    }
+
+    /// Returns the number of active checkpoints
+    pub fn depth(&self) -> usize {
+        self.checkpoints.len()
+    }
+
+    /// Undoes all edits since the last checkpoint and discards it. Returns false (and changes
+    /// nothing) if there is no active checkpoint.
+    pub fn rollback(&mut self) -> bool {
+        match self.checkpoints.pop() {
+            Some(len) => {
+                self.undo_until(len);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Undoes all recorded edits, i.e. restores the graph as it was passed to [`EditLog::new`],
+    /// and discards all checkpoints
+    pub fn rollback_all(&mut self) {
+        self.checkpoints.clear();
+        self.undo_until(0);
+    }
+
+    fn undo_until(&mut self, len: usize) {
+        while self.edits.len() > len {
+            match self.edits.pop().unwrap() {
+                Edit::Added((u, v)) => self.graph.remove_edge(u, v),
+                Edit::Removed((u, v)) => self.graph.add_edge(u, v),
+            }
+        }
+    }
+
+    /// Adds the edge (u, v).
+    /// ** Panics if the edge is already contained **
+    pub fn add_edge(&mut self, u: Node, v: Node) {
+        self.graph.add_edge(u, v);
+        self.edits.push(Edit::Added((u, v)));
+    }
+
+    /// Adds the edge (u, v) unless it already exists. Returns true iff the edge was added.
+    pub fn try_add_edge(&mut self, u: Node, v: Node) -> bool {
+        let added = self.graph.try_add_edge(u, v);
+        if added {
+            self.edits.push(Edit::Added((u, v)));
+        }
+        added
+    }
+
+    /// Removes the edge (u, v).
+    /// ** Panics if the edge is not contained **
+    pub fn remove_edge(&mut self, u: Node, v: Node) {
+        self.graph.remove_edge(u, v);
+        self.edits.push(Edit::Removed((u, v)));
+    }
+
+    /// Removes the edge (u, v) if it exists. Returns true iff the edge was removed.
+    pub fn try_remove_edge(&mut self, u: Node, v: Node) -> bool {
+        let removed = self.graph.try_remove_edge(u, v);
+        if removed {
+            self.edits.push(Edit::Removed((u, v)));
+        }
+        removed
+    }
+
+    /// Removes all edges incident to u (including a self-loop)
+    pub fn remove_edges_at_node(&mut self, u: Node) {
+        let out_neighbors = self.graph.out_neighbors(u).collect_vec();
+        for v in out_neighbors {
+            self.remove_edge(u, v);
+        }
+
+        let in_neighbors = self.graph.in_neighbors(u).collect_vec();
+        for v in in_neighbors {
+            self.remove_edge(v, u);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::random_models::gnp::generate_gnp;
+    use rand::{Rng, SeedableRng};
+    use rand_pcg::Pcg64Mcg;
+
+    #[test]
+    fn rollback_restores_checkpoints() {
+        let mut graph = AdjArrayIn::from(&[(0, 1), (1, 2), (2, 0), (2, 2), (3, 1)]);
+        let original = graph.edges_vec();
+
+        let mut log = EditLog::new(&mut graph);
+        assert!(!log.rollback());
+        assert_eq!(log.checkpoint(), 1);
+        log.remove_edges_at_node(2);
+        log.add_edge(1, 3);
+        assert!(!log.try_add_edge(1, 3));
+        let intermediate = log.graph().edges_vec();
+        assert_eq!(intermediate, vec![(0, 1), (1, 3), (3, 1)]);
+
+        assert_eq!(log.checkpoint(), 2);
+        log.remove_edge(0, 1);
+        assert!(!log.try_remove_edge(0, 1));
+        assert!(log.try_add_edge(0, 3));
+        assert_eq!(log.depth(), 2);
+
+        assert!(log.rollback());
+        assert_eq!(log.graph().edges_vec(), intermediate);
+        assert!(log.rollback());
+        assert_eq!(log.graph().edges_vec(), original);
+        assert_eq!(log.depth(), 0);
+        assert_eq!(graph.edges_vec(), original);
+    }
+
+    #[test]
+    fn rollback_random_edits() {
+        let mut rng = Pcg64Mcg::seed_from_u64(3);
+        let mut graph: AdjArrayIn = generate_gnp(&mut rng, 30, 0.1);
+        let original = graph.edges_vec();
+
+        let mut log = EditLog::new(&mut graph);
+        let mut states = Vec::new();
+        for _ in 0..5 {
+            states.push(log.graph().edges_vec());
+            log.checkpoint();
+            for _ in 0..20 {
+                let u = rng.gen_range(0..30);
+                let v = rng.gen_range(0..30);
+                match rng.gen_range(0..3) {
+                    0 => {
+                        log.try_add_edge(u, v);
+                    }
+                    1 => {
+                        log.try_remove_edge(u, v);
+                    }
+                    _ => log.remove_edges_at_node(u),
+                }
+            }
+        }
+
+        while let Some(state) = states.pop() {
+            assert!(log.rollback());
+            assert_eq!(log.graph().edges_vec(), state);
+        }
+
+        log.checkpoint();
+        log.remove_edges_at_node(0);
+        log.rollback_all();
+        assert_eq!(graph.edges_vec(), original);
+    }
+}
@@ -28,6 +28,27 @@ macro_rules! impl_helper_graph_from_edges {
                 graph
             }
         }
+
+        impl FromIterator<Edge> for $t {
+            /// The number of nodes is inferred from the largest node id; see also `from_edges`
+            fn from_iter<T: IntoIterator<Item = Edge>>(edges: T) -> Self {
+                let edges: Vec<Edge> = edges.into_iter().collect();
+                Self::from(&edges)
+            }
+        }
+
+        impl $t {
+            /// Creates a graph with exactly `n` nodes (including isolated nodes with ids larger than
+            /// any edge endpoint) and the given edges.
+            /// ** Panics if an edge has an endpoint `>= n` **
+            pub fn from_edges<T: IntoIterator<Item = Edge>>(n: usize, edges: T) -> Self {
+                let mut graph = Self::new(n);
+                for (u, v) in edges {
+                    graph.add_edge(u, v);
+                }
+                graph
+            }
+        }
     };
 }
 
@@ -190,6 +211,19 @@ macro_rules! test_helper_from_edges {
             edges.sort();
             assert_eq!(edges, graph.edges_vec());
         }
+
+        #[test]
+        fn graph_from_edge_iterator() {
+            let graph: $t = (0..4).map(|u| (u, (u + 1) % 4)).collect();
+            assert_eq!(graph.number_of_nodes(), 4);
+            assert_eq!(graph.edges_vec(), vec![(0, 1), (1, 2), (2, 3), (3, 0)]);
+
+            let graph = $t::from_edges(10, [(0, 1)]);
+            assert_eq!(graph.number_of_nodes(), 10);
+            assert_eq!(graph.edges_vec(), vec![(0, 1)]);
+
+            assert_eq!($t::from_edges(3, []).number_of_nodes(), 3);
+        }
     };
 }
 
@@ -233,6 +267,59 @@ macro_rules! test_helper_adjacency_list {
             assert_eq!(nb.len() as Node, graph.out_degree(3));
             assert_eq!(graph.edges_vec(), edges);
         }
+
+        #[test]
+        fn edges() {
+            let mut edges = vec![(0, 3), (1, 1), (1, 3), (2, 3), (3, 2), (3, 4), (3, 5)];
+            let graph = $t::from(&edges);
+            assert_eq!(graph.edges().count(), graph.number_of_edges());
+
+            let mut graph_edges: Vec<_> = graph.edges().collect();
+            graph_edges.sort();
+            edges.sort();
+            assert_eq!(graph_edges, edges);
+        }
+
+        #[test]
+        fn in_neighbors_by_scan() {
+            // {0,1} and {4,5} are scc pairs, 2 is a loop, 3 is a singleton
+            let edges = vec![(0, 1), (1, 0), (2, 2), (4, 5), (5, 4), (3, 5), (2, 5)];
+            let graph = $t::from(&edges);
+            for v in graph.vertices() {
+                let mut expected: Vec<_> = edges
+                    .iter()
+                    .filter(|&&(_, w)| w == v)
+                    .map(|&(u, _)| u)
+                    .collect();
+                expected.sort();
+                let mut in_neighbors: Vec<_> = graph.in_neighbors_by_scan(v).collect();
+                in_neighbors.sort();
+                assert_eq!(in_neighbors, expected);
+            }
+        }
+
+        #[test]
+        fn complement() {
+            let edges = vec![(0, 1), (1, 2), (2, 0), (2, 3), (3, 2)];
+            let graph = $t::from(&edges);
+            let n = graph.len();
+
+            let complement = graph.complement(false);
+            assert_eq!(complement.len(), n);
+            assert_eq!(
+                complement.number_of_edges() + graph.number_of_edges(),
+                n * (n - 1)
+            );
+            assert!(edges.iter().all(|&(u, v)| !complement.has_edge(u, v)));
+            assert!(!complement.vertices().any(|u| complement.has_edge(u, u)));
+
+            let complement = graph.complement(true);
+            assert_eq!(
+                complement.number_of_edges() + graph.number_of_edges(),
+                n * n
+            );
+            assert!(complement.vertices().all(|u| complement.has_edge(u, u)));
+        }
     };
 }
 
@@ -252,6 +339,71 @@ macro_rules! test_helper_adjacency_list_in {
             assert_eq!(3, graph.in_degree(3));
             assert_eq!(5, graph.total_degree(3));
         }
+
+        #[test]
+        fn degree_histogram() {
+            let graph = $t::from(&[
+                (0, 1),
+                (1, 2),
+                (1, 4),
+                (1, 5),
+                (2, 6),
+                (2, 3),
+                (3, 2),
+                (3, 7),
+                (4, 0),
+                (4, 5),
+                (5, 6),
+                (6, 5),
+                (7, 3),
+                (7, 6),
+            ]);
+            assert_eq!(graph.number_of_edges(), 14);
+
+            let (in_hist, out_hist) = graph.degree_histogram();
+            assert_eq!(in_hist, vec![0, 4, 2, 2]);
+            assert_eq!(out_hist, vec![0, 3, 4, 1]);
+
+            let (in_hist, out_hist) = $t::new(0).degree_histogram();
+            assert!(in_hist.is_empty() && out_hist.is_empty());
+        }
+
+        #[test]
+        fn degeneracy_ordering() {
+            // triangle 0 -> 1 -> 2 -> 0 with the pendant edge (3, 0)
+            let graph = $t::from(&[(0, 1), (1, 2), (2, 0), (3, 0)]);
+            assert_eq!(graph.degeneracy_ordering(), (vec![3, 0, 1, 2], 2));
+
+            // in a bidirected K4 each node has total degree 6 initially
+            let mut edges = Vec::new();
+            for u in 0..4 {
+                for v in 0..4 {
+                    if u != v {
+                        edges.push((u, v));
+                    }
+                }
+            }
+            let graph = $t::from(&edges);
+            assert_eq!(graph.degeneracy_ordering(), (vec![0, 1, 2, 3], 6));
+
+            assert_eq!($t::new(0).degeneracy_ordering(), (vec![], 0));
+        }
+
+        #[test]
+        fn contract_nodes() {
+            // contracting two nodes of a triangle leaves a 2-cycle
+            let mut graph = $t::from(&[(0, 1), (1, 2), (2, 0)]);
+            graph.contract_nodes(0, 1);
+            assert_eq!(graph.len(), 3);
+            assert_eq!(graph.edges_vec(), vec![(0, 2), (2, 0)]);
+            assert_eq!(graph.total_degree(1), 0);
+
+            // parallel edges are merged and a self-loop of the removed node is moved
+            let mut graph = $t::from(&[(0, 2), (1, 2), (3, 0), (3, 1), (1, 1), (1, 0)]);
+            graph.contract_nodes(0, 1);
+            assert_eq!(graph.edges_vec(), vec![(0, 0), (0, 2), (3, 0)]);
+            assert_eq!(graph.in_degree(0), 2);
+        }
     };
 }
 
@@ -269,6 +421,47 @@ macro_rules! test_helper_adjacency_test {
                 }
             }
 
+            // has_edge on the SCC example of the connectivity tests
+            {
+                let edges = vec![
+                    (0, 1),
+                    (1, 2),
+                    (1, 4),
+                    (1, 5),
+                    (2, 6),
+                    (2, 3),
+                    (3, 2),
+                    (3, 7),
+                    (4, 0),
+                    (4, 5),
+                    (5, 6),
+                    (6, 5),
+                    (7, 3),
+                    (7, 6),
+                ];
+                let graph = $t::from(&edges);
+                for u in graph.vertices() {
+                    for v in graph.vertices() {
+                        assert_eq!(graph.has_edge(u, v), edges.contains(&(u, v)));
+                    }
+                }
+
+                let two_cycles: Vec<_> = edges
+                    .iter()
+                    .filter(|&&(u, v)| u < v && graph.has_edge(u, v) && graph.has_edge(v, u))
+                    .collect();
+                assert_eq!(two_cycles, vec![&(2, 3), &(3, 7), &(5, 6)]);
+            }
+
+            // bidirectional_edges
+            {
+                let graph = $t::from(&[(0, 1), (1, 2), (2, 1), (3, 0), (3, 3), (4, 3), (3, 4)]);
+                assert_eq!(graph.bidirectional_edges(), vec![(1, 2), (3, 4)]);
+
+                let graph = $t::from(&[(0, 1), (1, 2), (2, 0)]);
+                assert!(graph.bidirectional_edges().is_empty());
+            }
+
             // has_self_loop
             {
                 let edges = vec![(0, 3), (1, 3), (2, 3), (3, 4), (3, 5)];
@@ -405,6 +598,23 @@ macro_rules! test_helper_graph_edge_editing {
             }
         }
 
+        #[test]
+        fn isolate_node() {
+            let org_graph = $t::from(&[(0, 3), (1, 3), (2, 3), (3, 3), (3, 4), (3, 5), (4, 5)]);
+            let mut graph = org_graph.clone();
+            graph.isolate_node(3);
+
+            assert_eq!(graph.len(), org_graph.len());
+            assert_eq!(graph.out_neighbors(3).count(), 0);
+            assert!(!graph.vertices().any(|u| graph.has_edge(u, 3)));
+
+            let expected: Vec<_> = org_graph
+                .edges_iter()
+                .filter(|&(u, v)| u != 3 && v != 3)
+                .collect();
+            assert_eq!(graph.edges_vec(), expected);
+        }
+
         #[test]
         fn contract_node() {
             // path
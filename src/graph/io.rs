@@ -6,10 +6,11 @@ use std::str::FromStr;
 
 pub use dot::DotWrite;
 pub use metis::{MetisRead, MetisWrite};
-pub use pace::{PaceRead, PaceWrite};
+pub use pace::{read_pace, write_pace_solution, write_pace_solution_bitset, PaceRead, PaceWrite};
 
 pub mod dot {
     use super::*;
+    use crate::bitset::BitSet;
     use fxhash::FxHashSet;
     use itertools::Itertools;
     use std::collections::HashSet;
@@ -25,6 +26,22 @@ pub mod dot {
 
         /// produces a minimalistic DOT representation of the graph
         fn try_write_dot<W: Write>(&self, writer: W) -> Result<(), std::io::Error>;
+
+        /// Returns the output of [`DotWrite::try_write_dot`] as a String
+        fn to_dot(&self) -> String {
+            let mut buffer = Vec::new();
+            self.try_write_dot(&mut buffer).unwrap();
+            String::from_utf8(buffer).unwrap()
+        }
+
+        /// Returns the output of [`DotWrite::try_write_dot_with_solution`] as a String, where
+        /// all nodes in `selected` are highlighted
+        fn to_dot_highlight(&self, selected: &BitSet) -> String {
+            let mut buffer = Vec::new();
+            self.try_write_dot_with_solution(&mut buffer, selected.iter().map(|u| u as Node))
+                .unwrap();
+            String::from_utf8(buffer).unwrap()
+        }
     }
 
     const ATTRIB_NODE_IN_DFVS: &str = "[color=red]";
@@ -252,6 +269,7 @@ pub mod metis {
 
 pub mod pace {
     use super::*;
+    use crate::bitset::BitSet;
     pub trait PaceRead: Sized {
         fn try_read_pace<T: BufRead>(buf: T) -> Result<Self, std::io::Error>;
     }
@@ -314,6 +332,91 @@ pub mod pace {
         }
     }
 
+    /// Reads a PACE 2022 DFVS instance in adjacency list format: after the `p dfvs n m` header,
+    /// the i-th (1-based) non-comment line lists the 1-based out-neighbors of node i. Lines
+    /// starting with `c` or `%` are treated as comments. In contrast to [`PaceRead::try_read_pace`],
+    /// which expects one `u v` pair per line, the line itself encodes the source of each edge.
+    pub fn read_pace<R: BufRead>(reader: R) -> std::io::Result<AdjListMatrix> {
+        let error = |msg: String| Err(std::io::Error::new(ErrorKind::InvalidData, msg));
+
+        let mut lines = reader.lines();
+        let mut next_line = || -> Option<std::io::Result<String>> {
+            loop {
+                match lines.next()? {
+                    Ok(line) if line.starts_with('c') || line.starts_with('%') => continue,
+                    other => return Some(other),
+                }
+            }
+        };
+
+        let (n, m) = match next_line() {
+            Some(header) => {
+                let header = header?;
+                let elements: Vec<_> = header.split_whitespace().collect();
+                if elements.len() != 4 || elements[0] != "p" || elements[1] != "dfvs" {
+                    return error(format!("Invalid header line {:?}", header));
+                }
+                let n = parse_order(&elements)?;
+                let m: usize = match elements[3].parse() {
+                    Ok(m) => m,
+                    Err(_) => return error("Invalid number of edges".into()),
+                };
+                (n, m)
+            }
+            None => return error("Cannot read header".into()),
+        };
+
+        let mut edges: Vec<Edge> = Vec::with_capacity(m);
+        let mut source: usize = 0;
+        while let Some(line) = next_line() {
+            let line = line?;
+            if source >= n {
+                if line.trim().is_empty() {
+                    continue;
+                }
+                return error("Too many adjacency lines".into());
+            }
+            for v in line.split_whitespace() {
+                edges.push((source as Node, parse_vertex(v, n)?));
+            }
+            source += 1;
+        }
+
+        if edges.len() != m {
+            return error(format!(
+                "Header announces {} edges, but {} were read",
+                m,
+                edges.len()
+            ));
+        }
+
+        edges.sort_unstable();
+        edges.dedup();
+        Ok(AdjListMatrix::from_slice(n as Node, &edges, true))
+    }
+
+    /// Writes a DFVS solution: the first line contains the number of deleted nodes, followed by
+    /// one line per node with its 1-based id.
+    pub fn write_pace_solution<W: Write>(mut writer: W, solution: &[Node]) -> std::io::Result<()> {
+        writeln!(writer, "{}", solution.len())?;
+        for u in solution {
+            writeln!(writer, "{}", u + 1)?;
+        }
+        Ok(())
+    }
+
+    /// Same as [`write_pace_solution`], but takes the solution as a BitSet over all nodes
+    pub fn write_pace_solution_bitset<W: Write>(
+        mut writer: W,
+        solution: &BitSet,
+    ) -> std::io::Result<()> {
+        writeln!(writer, "{}", solution.cardinality())?;
+        for u in solution.iter() {
+            writeln!(writer, "{}", u + 1)?;
+        }
+        Ok(())
+    }
+
     fn parse_vertex(v: &str, order: usize) -> Result<Node, std::io::Error> {
         match v.parse::<Node>() {
             Ok(u) => {
@@ -530,6 +633,41 @@ mod tests {
         }
     }
 
+    #[test]
+    fn to_dot() {
+        let graph = AdjListMatrix::from(&[
+            (0, 1),
+            (1, 2),
+            (1, 4),
+            (1, 5),
+            (2, 6),
+            (2, 3),
+            (3, 2),
+            (3, 7),
+            (4, 0),
+            (4, 5),
+            (5, 6),
+            (6, 5),
+            (7, 3),
+            (7, 6),
+            (7, 7),
+        ]);
+
+        let dot = graph.to_dot();
+        assert!(dot.starts_with("digraph {"));
+        assert!(dot.trim_end().ends_with('}'));
+        assert!(dot.contains(" v0 -> { v1 }"));
+        assert!(dot.contains(" v1 -> { v2 v4 v5 }"));
+        assert!(dot.contains(" v6 -> { v5 }"));
+        assert!(dot.contains(" v7 -> { v3 v6 v7 }"));
+
+        let selected = crate::bitset::BitSet::new_all_unset_but(graph.len(), [1u32, 7]);
+        let dot = graph.to_dot_highlight(&selected);
+        assert!(dot.starts_with("digraph {"));
+        assert!(dot.contains("1,7 [color=red]"));
+        assert!(dot.contains("k = 2"));
+    }
+
     #[test]
     fn read_metis_graph() {
         let data = "%test\n7 8 0\n%test\n2 4 5 6\n3 7\n6\n\n7 6\n".as_bytes();
@@ -594,6 +732,73 @@ mod tests {
         assert!(graph.has_edge(3, 5));
     }
 
+    #[test]
+    fn read_pace_adjacency_lists() {
+        let data = "c instance\np dfvs 7 9\n2 4 5 6\n3 7\nc comment\n7\n5 6\n\n\n\n".as_bytes();
+        let graph = read_pace(data).unwrap();
+
+        assert_eq!(graph.number_of_nodes(), 7);
+        assert_eq!(
+            graph.edges_vec(),
+            vec![
+                (0, 1),
+                (0, 3),
+                (0, 4),
+                (0, 5),
+                (1, 2),
+                (1, 6),
+                (2, 6),
+                (3, 4),
+                (3, 5)
+            ]
+        );
+
+        for buffer in [
+            "",                      // no header
+            "p dfvs 3\n",            // too short header
+            "p td 3 1\n2\n",         // wrong problem
+            "p dfvs 3 2\n2\n",       // too few edges
+            "p dfvs 3 1\n4\n",       // neighbor out of range
+            "p dfvs 3 1\n0\n",       // neighbor out of range
+            "p dfvs 2 2\n2\n1\n1\n", // too many lines
+            "p dfvs 2 1\na\n",       // invalid neighbor
+        ] {
+            assert!(
+                read_pace(buffer.as_bytes()).is_err(),
+                "Error not found in {}",
+                buffer
+            );
+        }
+    }
+
+    #[test]
+    fn write_pace_solutions() {
+        let parse = |buffer: Vec<u8>| -> Vec<Node> {
+            let text = String::from_utf8(buffer).unwrap();
+            let mut lines = text.lines().map(|l| l.parse::<Node>().unwrap());
+            let k = lines.next().unwrap() as usize;
+            let nodes: Vec<Node> = lines.map(|u| u - 1).collect();
+            assert_eq!(k, nodes.len());
+            nodes
+        };
+
+        let solution = vec![0, 4, 17];
+
+        let mut buffer = vec![];
+        write_pace_solution(&mut buffer, &solution).unwrap();
+        assert_eq!(String::from_utf8(buffer.clone()).unwrap(), "3\n1\n5\n18\n");
+        assert_eq!(parse(buffer), solution);
+
+        let mut buffer = vec![];
+        let bitset = crate::bitset::BitSet::new_all_unset_but(20, solution.iter().copied());
+        write_pace_solution_bitset(&mut buffer, &bitset).unwrap();
+        assert_eq!(parse(buffer), solution);
+
+        let mut buffer = vec![];
+        write_pace_solution(&mut buffer, &[]).unwrap();
+        assert_eq!(String::from_utf8(buffer).unwrap(), "0\n");
+    }
+
     macro_rules! round_trip_test {
         ($fnname:ident, $r:ident, $w:ident) => {
             #[test]
@@ -1,9 +1,11 @@
 pub mod adj_array;
 pub mod adj_array_undir;
 pub mod adj_list_matrix;
+pub mod builder;
 pub mod complete_subgraphs;
 pub mod connectivity;
 pub mod digest;
+pub mod edit_log;
 pub mod generators;
 pub(super) mod graph_macros;
 pub mod hash_graph;
@@ -23,6 +25,7 @@ pub type Edge = (Node, Node);
 pub use adj_array::{AdjArray, AdjArrayIn};
 pub use adj_array_undir::AdjArrayUndir;
 pub use adj_list_matrix::{AdjListMatrix, AdjListMatrixIn};
+pub use builder::GraphBuilder;
 pub use complete_subgraphs::CompleteSubgraphEnumerator;
 
 pub use self::digest::GraphDigest;
@@ -37,7 +40,10 @@ pub use subgraph::*;
 pub use traversal::*;
 pub use unique_node_stack::UniqueNodeStack;
 
+use crate::bitset::BitSet;
 use fxhash::FxHashSet;
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use std::ops::Range;
 
 /// Provides getters pertaining to the size of a graph
@@ -89,6 +95,64 @@ pub trait AdjacencyListIn: AdjacencyList {
     fn total_degree(&self, u: Node) -> Node {
         self.in_degree(u) + self.out_degree(u)
     }
+
+    /// Returns the number of nodes with in-degree d and out-degree d, respectively, at index d.
+    /// Each vector is as long as the maximum degree plus one and empty for graphs without nodes.
+    fn degree_histogram(&self) -> (Vec<usize>, Vec<usize>) {
+        let mut in_hist = Vec::new();
+        let mut out_hist = Vec::new();
+        for u in self.vertices() {
+            for (hist, degree) in [
+                (&mut in_hist, self.in_degree(u)),
+                (&mut out_hist, self.out_degree(u)),
+            ] {
+                let degree = degree as usize;
+                if hist.len() <= degree {
+                    hist.resize(degree + 1, 0);
+                }
+                hist[degree] += 1;
+            }
+        }
+        (in_hist, out_hist)
+    }
+
+    /// Repeatedly removes a node of minimum total degree (ties are broken by the smaller id) and
+    /// returns the nodes in removal order together with the degeneracy, i.e. the maximum total
+    /// degree a node had at the time of its removal. Self-loops count twice.
+    fn degeneracy_ordering(&self) -> (Vec<Node>, usize) {
+        let mut degrees: Vec<Node> = vec![0; self.len()];
+        for u in self.vertices() {
+            degrees[u as usize] = self.total_degree(u);
+        }
+
+        let mut removed = vec![false; self.len()];
+        let mut heap: BinaryHeap<_> = self
+            .vertices()
+            .map(|u| Reverse((degrees[u as usize], u)))
+            .collect();
+
+        let mut order = Vec::with_capacity(self.len());
+        let mut degeneracy = 0;
+        while let Some(Reverse((degree, u))) = heap.pop() {
+            // skip outdated entries
+            if removed[u as usize] || degree != degrees[u as usize] {
+                continue;
+            }
+
+            removed[u as usize] = true;
+            order.push(u);
+            degeneracy = degeneracy.max(degree as usize);
+
+            for v in self.out_neighbors(u).chain(self.in_neighbors(u)) {
+                if !removed[v as usize] {
+                    degrees[v as usize] -= 1;
+                    heap.push(Reverse((degrees[v as usize], v)));
+                }
+            }
+        }
+
+        (order, degeneracy)
+    }
 }
 
 pub trait AdjacencyListUndir: AdjacencyList {
@@ -130,6 +194,22 @@ pub trait AdjacencyList: GraphOrder + Sized {
     /// Returns the number of outgoing edges from *u*
     fn out_degree(&self, u: Node) -> Node;
 
+    /// Returns an iterator over all edges (including self-loops) in no particular order. In contrast
+    /// to [`AdjacencyList::edges_iter`], it neither allocates nor sorts.
+    fn edges(&self) -> impl Iterator<Item = Edge> + '_ {
+        self.vertices()
+            .flat_map(move |u| self.out_neighbors(u).map(move |v| (u, v)))
+    }
+
+    /// Returns an iterator over the incoming neighbors of `v` (in the order of
+    /// [`GraphOrder::vertices`]), computed by scanning the out-neighbors of all nodes in time
+    /// O(n + m). Graphs implementing
+    /// [`AdjacencyListIn`] answer this query directly via [`AdjacencyListIn::in_neighbors`].
+    fn in_neighbors_by_scan(&self, v: Node) -> impl Iterator<Item = Node> + '_ {
+        self.vertices()
+            .filter(move |&u| self.out_neighbors(u).any(|w| w == v))
+    }
+
     /// Returns an iterator over all edges in the graph in increasing order.
     fn edges_iter(&self) -> EdgeIterator<Self> {
         let mut vertices: Vec<Node> = self.vertices().collect();
@@ -147,6 +227,22 @@ pub trait AdjacencyList: GraphOrder + Sized {
     fn edges_vec(&self) -> Vec<Edge> {
         self.edges_iter().collect()
     }
+
+    /// Returns the complement graph which contains the edge (u, v) with u != v iff the edge is
+    /// missing in self. If `self_loops` is set, the same applies to self-loops; otherwise the
+    /// complement contains none.
+    fn complement(&self, self_loops: bool) -> AdjListMatrix {
+        let mut complement = AdjListMatrix::new(self.len());
+        for u in self.vertices() {
+            let neighbors = BitSet::new_all_unset_but(self.len(), self.out_neighbors(u));
+            for v in self.vertices() {
+                if !neighbors[v as usize] && (self_loops || u != v) {
+                    complement.add_edge(u, v);
+                }
+            }
+        }
+        complement
+    }
 }
 
 /// Iterates over the edges of a graph in sorted order
@@ -191,6 +287,25 @@ pub trait AdjacencyTest {
     {
         self.vertices().any(|u| self.has_edge(u, u))
     }
+
+    /// Returns all pairs (u, v) with u < v such that the graph contains both edges (u, v) and
+    /// (v, u), i.e. the 2-cycles of the graph, in increasing order
+    fn bidirectional_edges(&self) -> Vec<Edge>
+    where
+        Self: AdjacencyList,
+    {
+        let mut edges: Vec<Edge> = self
+            .vertices()
+            .flat_map(|u| {
+                self.out_neighbors(u)
+                    .filter(move |&v| u < v && self.has_edge(v, u))
+                    .map(move |v| (u, v))
+            })
+            .collect();
+        edges.sort_unstable();
+        edges.dedup();
+        edges
+    }
 }
 
 /// Provides efficient tests whether an undirected edge exists
@@ -259,6 +374,12 @@ pub trait GraphEdgeEditing: GraphNew {
         self.remove_edges_into_node(u);
     }
 
+    /// Removes all edges incident to `v` but keeps it as an isolated node. In contrast to
+    /// [`InducedSubgraph::vertex_induced`], the ids of all nodes remain valid.
+    fn isolate_node(&mut self, v: Node) {
+        self.remove_edges_at_node(v);
+    }
+
     /// Removes all edges into node u, i.e. post-condition the in-degree is 0
     fn remove_edges_into_node(&mut self, u: Node);
 
@@ -275,6 +396,36 @@ pub trait GraphEdgeEditing: GraphNew {
         }
     }
 
+    /// Merges node `remove` into node `keep`: every edge (u, remove) is rerouted to (u, keep) and
+    /// every edge (remove, v) to (keep, v); parallel edges are merged. Edges between `keep` and
+    /// `remove` are dropped (i.e. they do not produce a self-loop at `keep`), while a self-loop at
+    /// `remove` becomes a self-loop at `keep`. Node ids do not shift: `remove` stays in the graph
+    /// as an isolated node.
+    /// ** Panics if `keep == remove` **
+    fn contract_nodes(&mut self, keep: Node, remove: Node)
+    where
+        Self: AdjacencyListIn,
+    {
+        assert_ne!(keep, remove);
+        let out_neighbors: Vec<Node> = self.out_neighbors(remove).collect();
+        let in_neighbors: Vec<Node> = self.in_neighbors(remove).collect();
+        self.remove_edges_at_node(remove);
+
+        for v in out_neighbors {
+            if v == remove {
+                self.try_add_edge(keep, keep);
+            } else if v != keep {
+                self.try_add_edge(keep, v);
+            }
+        }
+
+        for u in in_neighbors {
+            if u != remove && u != keep {
+                self.try_add_edge(u, keep);
+            }
+        }
+    }
+
     /// Removes all edges into and out of node `u` and connects every in-neighbor with every out-neighbor.
     /// Returns all nodes that got a self-loop during the process.
     fn contract_node(&mut self, u: Node) -> Vec<Node>;
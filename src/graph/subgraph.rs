@@ -51,6 +51,16 @@ pub trait InducedSubgraph: Sized {
         self.vertex_induced_as(vertices)
     }
 
+    /// Returns the subgraph induced by all nodes i with keep\[i\] == true, together with the
+    /// original id of each of its nodes. The nodes keep their relative order, i.e. the j-th node
+    /// of the subgraph is the j-th node set in `keep`, which allows to translate a solution of
+    /// the subgraph back via indexing.
+    fn induced_subgraph(&self, keep: &BitSet) -> (AdjListMatrix, Vec<Node>) {
+        let (subgraph, _): (AdjListMatrix, NodeMapper) = self.vertex_induced_as(keep);
+        let original_ids = keep.iter().map(|u| u as Node).collect();
+        (subgraph, original_ids)
+    }
+
     /// Creates a subgraph where all nodes without edges are removed
     fn remove_disconnected_verts(&self) -> (Self, NodeMapper)
     where
@@ -115,6 +125,25 @@ impl<G: AdjacencyList + GraphEdgeEditing + GraphOrder + Clone + GraphNew> SubGra
     }
 }
 
+pub trait Transpose: AdjacencyList {
+    /// Returns a new graph on the same number of nodes (including isolated ones) where every
+    /// edge (u, v) is replaced by (v, u)
+    fn transpose_as<Gout: GraphNew + GraphEdgeEditing>(&self) -> Gout {
+        let mut result = Gout::new(self.len());
+        for (u, v) in self.edges_iter() {
+            result.add_edge(v, u);
+        }
+        result
+    }
+
+    /// Returns the reversed graph as an [`AdjListMatrix`]
+    fn transpose(&self) -> AdjListMatrix {
+        self.transpose_as()
+    }
+}
+
+impl<G: AdjacencyList> Transpose for G {}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
@@ -164,6 +193,20 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn test_induced_subgraph() {
+        let g = AdjArrayIn::from(&[(0, 0), (1, 2), (2, 3), (3, 4), (4, 1), (3, 3)]);
+        let keep = BitSet::new_all_set_but(g.len(), [0u32, 3]);
+        let (ind, original_ids) = g.induced_subgraph(&keep);
+
+        assert_eq!(original_ids, vec![1, 2, 4]);
+        assert_eq!(ind.number_of_nodes(), 3);
+        assert_eq!(ind.edges_vec(), vec![(0, 1), (2, 0)]);
+        for (u, v) in ind.edges_iter() {
+            assert!(g.has_edge(original_ids[u as usize], original_ids[v as usize]));
+        }
+    }
+
     #[test]
     fn test_sub_graph() {
         let mut g = AdjListMatrixIn::new(6);
@@ -181,4 +224,42 @@ pub mod tests {
         }
         assert_eq!(sub_graph.total_degree(5), 0);
     }
+
+    #[test]
+    fn test_transpose() {
+        let mut edges = [
+            (0, 1),
+            (1, 2),
+            (1, 4),
+            (1, 5),
+            (2, 6),
+            (2, 3),
+            (3, 2),
+            (3, 7),
+            (4, 0),
+            (4, 5),
+            (5, 6),
+            (6, 5),
+            (7, 3),
+            (7, 6),
+        ];
+        let mut g = AdjArrayIn::new(10);
+        g.add_edges(&edges);
+
+        let t = g.transpose();
+        assert_eq!(t.number_of_nodes(), 10);
+        assert_eq!(t.number_of_edges(), edges.len());
+        for &(u, v) in &edges {
+            assert!(t.has_edge(v, u));
+        }
+        for u in g.vertices() {
+            assert_eq!(t.out_degree(u), g.in_degree(u));
+        }
+
+        let tt: AdjArrayIn = t.transpose_as();
+        assert_eq!(tt.number_of_nodes(), g.number_of_nodes());
+        edges.sort_unstable();
+        assert_eq!(tt.edges_vec(), edges);
+        assert_eq!(tt.edges_vec(), g.edges_vec());
+    }
 }
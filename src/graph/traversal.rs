@@ -518,6 +518,167 @@ pub trait Traversal: AdjacencyList + Sized {
         self.topo_search().count() == self.len()
     }
 
+    /// Returns the nodes in a topological order, i.e. for each edge (u, v) node u precedes v,
+    /// or None if the graph contains a cycle
+    fn topological_order(&self) -> Option<Vec<Node>> {
+        let order: Vec<Node> = self.topo_search().collect();
+        if order.len() == self.len() {
+            Some(order)
+        } else {
+            None
+        }
+    }
+
+    /// Returns the nodes of some directed cycle in traversal order (i.e. there are edges between
+    /// consecutive nodes and from the last to the first node), or None if the graph is acyclic.
+    /// A self-loop is reported as a cycle containing a single node.
+    ///
+    /// # Example
+    /// ```
+    /// use dfvs::graph::{AdjListMatrix, Traversal};
+    /// let graph = AdjListMatrix::from(&[(0, 1), (1, 2), (2, 1)]);
+    /// let mut cycle = graph.find_cycle().unwrap();
+    /// cycle.sort();
+    /// assert_eq!(cycle, [1, 2]);
+    /// ```
+    fn find_cycle(&self) -> Option<Vec<Node>> {
+        let mut visited = BitSet::new(self.len());
+        let mut on_path = BitSet::new(self.len());
+        let mut path: Vec<Node> = Vec::new();
+        let mut neighbors_on_path: Vec<Self::Iter<'_>> = Vec::new();
+
+        for start in self.vertices() {
+            if visited[start as usize] {
+                continue;
+            }
+
+            visited.set_bit(start as usize);
+            on_path.set_bit(start as usize);
+            path.push(start);
+            neighbors_on_path.push(self.out_neighbors(start));
+
+            while let Some(neighbors) = neighbors_on_path.last_mut() {
+                if let Some(v) = neighbors.next() {
+                    if on_path[v as usize] {
+                        // back edge closes a cycle along the current path
+                        let begin = path.iter().rposition(|&u| u == v).unwrap();
+                        return Some(path.split_off(begin));
+                    }
+
+                    if !visited.set_bit(v as usize) {
+                        on_path.set_bit(v as usize);
+                        path.push(v);
+                        neighbors_on_path.push(self.out_neighbors(v));
+                    }
+                } else {
+                    neighbors_on_path.pop();
+                    on_path.unset_bit(path.pop().unwrap() as usize);
+                }
+            }
+        }
+
+        None
+    }
+
+    /// Returns the length of a shortest directed cycle, or None if the graph is acyclic.
+    /// A self-loop has length 1. Runs a BFS from each node and hence takes time O(n * (n + m)).
+    fn directed_girth(&self) -> Option<usize> {
+        self.directed_girth_with_bound(None)
+    }
+
+    /// Same as [`Traversal::directed_girth`], but returns as soon as a cycle of length at most
+    /// `stop_at` is found, even if it is not a shortest one.
+    fn directed_girth_with_bound(&self, stop_at: Option<usize>) -> Option<usize> {
+        let stop_at = stop_at.unwrap_or(1).max(1);
+        let mut best: Option<usize> = None;
+
+        let mut distance = vec![usize::MAX; self.len()];
+        let mut touched: Vec<Node> = Vec::new();
+        let mut queue: VecDeque<Node> = VecDeque::new();
+
+        for start in self.vertices() {
+            distance[start as usize] = 0;
+            touched.push(start);
+            queue.push_back(start);
+
+            'bfs: while let Some(u) = queue.pop_front() {
+                let length = distance[u as usize] + 1;
+                if best.is_some_and(|b| length >= b) {
+                    break; // cannot improve on the best cycle found so far
+                }
+
+                for v in self.out_neighbors(u) {
+                    if v == start {
+                        best = Some(length);
+                        break 'bfs;
+                    }
+                    if distance[v as usize] == usize::MAX {
+                        distance[v as usize] = length;
+                        touched.push(v);
+                        queue.push_back(v);
+                    }
+                }
+            }
+
+            if best.is_some_and(|b| b <= stop_at) {
+                return best;
+            }
+
+            queue.clear();
+            for u in touched.drain(..) {
+                distance[u as usize] = usize::MAX;
+            }
+        }
+
+        best
+    }
+
+    /// Returns for each node u the set of nodes reachable from u via a non-empty path. Hence, u
+    /// is contained in its own set iff it lies on a cycle (including self-loops).
+    ///
+    /// The sets are computed by a Warshall-like fixpoint on bitsets in time O(n^3 / 64).
+    ///
+    /// # Example
+    /// ```
+    /// use dfvs::graph::{AdjListMatrix, Traversal};
+    /// let graph = AdjListMatrix::from(&[(0, 1), (1, 2), (2, 1)]);
+    /// let reach = graph.reachability();
+    /// assert_eq!(reach[0].iter().collect::<Vec<_>>(), [1, 2]);
+    /// assert!(!reach[0][0] && reach[1][1]);
+    /// ```
+    fn reachability(&self) -> Vec<BitSet> {
+        let n = self.len();
+        let mut reach: Vec<BitSet> = self
+            .vertices()
+            .map(|u| BitSet::new_all_unset_but(n, self.out_neighbors(u)))
+            .collect();
+
+        // after iteration k, reach[u] contains all nodes reachable via intermediate nodes < k
+        for k in 0..n {
+            let row_k = std::mem::take(&mut reach[k]);
+            for (u, row_u) in reach.iter_mut().enumerate() {
+                if u != k && row_u[k] {
+                    row_u.or(&row_k);
+                }
+            }
+            reach[k] = row_k;
+        }
+
+        reach
+    }
+
+    /// Returns the transitive closure of the graph, i.e. a graph containing edge (u, v) with
+    /// u != v iff v is reachable from u (see [`Traversal::reachability`])
+    fn transitive_closure(&self) -> AdjListMatrix {
+        let mut closure = AdjListMatrix::new(self.len());
+        for (u, row) in self.reachability().iter().enumerate() {
+            for v in row.iter().filter(|&v| v != u) {
+                closure.add_edge(u as Node, v as Node);
+            }
+        }
+        closure
+    }
+
     /// Returns true iff there exists a directed path from u to u itself, i.e. if u is part of a
     /// non-trivial SCC
     ///
@@ -593,11 +754,86 @@ pub trait Traversal: AdjacencyList + Sized {
 
 impl<T: AdjacencyList + Sized> Traversal for T {}
 
+/// Returns true iff removing the nodes of `solution` from `graph` leaves an acyclic graph (see
+/// [`Traversal::is_acyclic`]). Unlike computing the induced subgraph first, no graph is
+/// materialized.
+pub fn is_dfvs<G: AdjacencyList>(graph: &G, solution: &[Node]) -> bool {
+    let removed = BitSet::new_all_unset_but(graph.len(), solution.iter().copied());
+
+    let mut in_degrees = vec![0usize; graph.len()];
+    for (u, v) in graph.edges_iter() {
+        if !removed[u as usize] && !removed[v as usize] {
+            in_degrees[v as usize] += 1;
+        }
+    }
+
+    let mut stack: Vec<Node> = graph
+        .vertices()
+        .filter(|&u| !removed[u as usize] && in_degrees[u as usize] == 0)
+        .collect();
+    let mut num_sorted = 0;
+    while let Some(u) = stack.pop() {
+        num_sorted += 1;
+        for v in graph.out_neighbors(u) {
+            if removed[v as usize] {
+                continue;
+            }
+            in_degrees[v as usize] -= 1;
+            if in_degrees[v as usize] == 0 {
+                stack.push(v);
+            }
+        }
+    }
+
+    num_sorted + removed.cardinality() == graph.len()
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
     use itertools::Itertools;
 
+    #[test]
+    fn reachability() {
+        let cycle = AdjListMatrix::from(&[(0, 1), (1, 2), (2, 3), (3, 4), (4, 0)]);
+        for row in cycle.reachability() {
+            assert!(row.full());
+        }
+
+        // 0 -> 1 -> 2 -> 3 with a self-loop at 4 and a 2-cycle {1, 2}
+        let graph = AdjListMatrix::from(&[(0, 1), (1, 2), (2, 1), (2, 3), (4, 4)]);
+        let reach: Vec<Vec<usize>> = graph
+            .reachability()
+            .iter()
+            .map(|row| row.iter().collect())
+            .collect();
+        assert_eq!(
+            reach,
+            vec![vec![1, 2, 3], vec![1, 2, 3], vec![1, 2, 3], vec![], vec![4]]
+        );
+
+        let on_cycle: Vec<Node> = graph
+            .reachability()
+            .iter()
+            .enumerate()
+            .filter(|(u, row)| row[*u])
+            .map(|(u, _)| u as Node)
+            .collect();
+        assert_eq!(on_cycle, [1, 2, 4]);
+    }
+
+    #[test]
+    fn transitive_closure() {
+        let path = AdjListMatrix::from(&[(0, 1), (1, 2)]);
+        let closure = path.transitive_closure();
+        assert_eq!(closure.edges_vec(), vec![(0, 1), (0, 2), (1, 2)]);
+
+        // self-loops and the diagonal of cycles are dropped
+        let graph = AdjListMatrix::from(&[(0, 1), (1, 0), (2, 2), (1, 3)]);
+        let closure = graph.transitive_closure();
+        assert_eq!(closure.edges_vec(), vec![(0, 1), (0, 3), (1, 0), (1, 3)]);
+    }
+
     #[test]
     fn bfs_order() {
         //  / 2 --- \
@@ -621,6 +857,55 @@ pub mod tests {
         }
     }
 
+    #[test]
+    fn bfs_dfs_visit_reachable_once() {
+        use crate::random_models::gnp::generate_random_dag;
+        use rand::SeedableRng;
+        use rand_pcg::Pcg64Mcg;
+
+        let mut rng = Pcg64Mcg::seed_from_u64(5);
+        for _ in 0..20 {
+            let graph: AdjListMatrix = generate_random_dag(&mut rng, 30, 0.1);
+            let reach = graph.reachability();
+
+            for start in graph.vertices() {
+                // distances computed layer by layer
+                let mut distance = vec![usize::MAX; graph.len()];
+                distance[start as usize] = 0;
+                let mut layer = vec![start];
+                let mut d = 0;
+                while !layer.is_empty() {
+                    d += 1;
+                    layer = layer
+                        .iter()
+                        .flat_map(|&u| graph.out_neighbors(u))
+                        .unique()
+                        .filter(|&v| distance[v as usize] == usize::MAX)
+                        .collect();
+                    for &v in &layer {
+                        distance[v as usize] = d;
+                    }
+                }
+
+                let mut expected: Vec<Node> =
+                    reach[start as usize].iter().map(|v| v as Node).collect();
+                expected.push(start);
+                expected.sort_unstable();
+
+                let bfs: Vec<Node> = graph.bfs(start).collect();
+                assert!(bfs
+                    .iter()
+                    .tuple_windows()
+                    .all(|(&u, &v)| distance[u as usize] <= distance[v as usize]));
+                assert_eq!(bfs.iter().copied().sorted().collect_vec(), expected);
+
+                let dfs: Vec<Node> = graph.dfs(start).collect();
+                assert_eq!(dfs[0], start);
+                assert_eq!(dfs.iter().copied().sorted().collect_vec(), expected);
+            }
+        }
+    }
+
     #[test]
     fn bfs_with_predecessor() {
         let graph = AdjListMatrix::from(&[(1, 2), (1, 0), (4, 3), (0, 5), (2, 4), (5, 4)]);
@@ -743,6 +1028,77 @@ pub mod tests {
         assert!(!graph.is_acyclic());
     }
 
+    #[test]
+    fn topological_order() {
+        let mut graph = AdjListMatrix::from(&[(0, 1), (1, 2), (1, 3), (1, 4), (3, 5), (3, 6)]);
+        let order = graph.topological_order().unwrap();
+        assert_eq!(order.len(), graph.len());
+        assert_eq!(
+            order.iter().copied().sorted().collect_vec(),
+            (0..7).collect_vec()
+        );
+
+        let mut position = vec![0; graph.len()];
+        for (i, &u) in order.iter().enumerate() {
+            position[u as usize] = i;
+        }
+        for (u, v) in graph.edges_iter() {
+            assert!(position[u as usize] < position[v as usize]);
+        }
+
+        graph.add_edge(6, 1); // introduce cycle
+        assert!(graph.topological_order().is_none());
+    }
+
+    #[test]
+    fn find_cycle() {
+        let assert_is_cycle = |graph: &AdjListMatrix, cycle: &[Node]| {
+            assert!(!cycle.is_empty());
+            assert_eq!(cycle.iter().unique().count(), cycle.len());
+            for (&u, &v) in cycle.iter().zip(cycle.iter().cycle().skip(1)) {
+                assert!(graph.has_edge(u, v));
+            }
+        };
+
+        let mut graph = AdjListMatrix::from(&[(4, 0), (0, 1), (1, 2), (2, 3), (3, 0), (3, 5)]);
+        let cycle = graph.find_cycle().unwrap();
+        assert_is_cycle(&graph, &cycle);
+        assert_eq!(cycle.iter().copied().sorted().collect_vec(), [0, 1, 2, 3]);
+
+        graph.remove_edge(3, 0);
+        assert!(graph.find_cycle().is_none());
+
+        graph.add_edge(5, 5);
+        assert_eq!(graph.find_cycle(), Some(vec![5]));
+
+        let graph = AdjListMatrix::from(&[(0, 1), (1, 2), (1, 3), (1, 4), (3, 5), (3, 6)]);
+        assert!(graph.find_cycle().is_none());
+    }
+
+    #[test]
+    fn directed_girth() {
+        let mut graph = AdjListMatrix::from(&[(0, 1), (1, 2), (1, 3), (1, 4), (3, 5), (3, 6)]);
+        assert_eq!(graph.directed_girth(), None);
+
+        graph.add_edge(6, 0); // cycle of length 4
+        assert_eq!(graph.directed_girth(), Some(4));
+
+        graph.add_edge(5, 1); // cycle of length 3
+        assert_eq!(graph.directed_girth(), Some(3));
+
+        graph.add_edge(4, 1); // cycle of length 2
+        assert_eq!(graph.directed_girth(), Some(2));
+
+        graph.add_edge(2, 2);
+        assert_eq!(graph.directed_girth(), Some(1));
+
+        // the triangle at nodes 0..3 is found first and suffices for the bound
+        let graph = AdjListMatrix::from(&[(0, 1), (1, 2), (2, 0), (3, 4), (4, 3)]);
+        assert_eq!(graph.directed_girth(), Some(2));
+        assert_eq!(graph.directed_girth_with_bound(Some(3)), Some(3));
+        assert_eq!(graph.directed_girth_with_bound(Some(2)), Some(2));
+    }
+
     #[test]
     fn node_on_cycle() {
         let mut graph = AdjListMatrix::from(&[(0, 1), (1, 2), (2, 3), (3, 0), (3, 4), (4, 5)]);
@@ -1,4 +1,6 @@
-use crate::graph::{AdjacencyListIn, GraphEdgeEditing, Node, Traversal};
+use crate::graph::{
+    AdjArrayIn, AdjacencyList, AdjacencyListIn, GraphEdgeEditing, GraphNew, Node, Traversal,
+};
 use fxhash::{FxBuildHasher, FxHashSet};
 use keyed_priority_queue::KeyedPriorityQueue;
 use std::iter::FromIterator;
@@ -108,6 +110,94 @@ impl<G: AdjacencyListIn + GraphEdgeEditing> Selector<G> for MaxDegreeSelector<G>
     }
 }
 
+/// Selects the vertex with the largest product of in- and out-degree, i.e. the vertex which is
+/// the center of the most paths of length two. Works analogously to [`MaxDegreeSelector`].
+pub struct MaxDegreeProductSelector<G: AdjacencyListIn + GraphEdgeEditing> {
+    graph: G,
+    queue: KeyedPriorityQueue<Node, u64, FxBuildHasher>,
+}
+
+impl<G: AdjacencyListIn + GraphEdgeEditing> MaxDegreeProductSelector<G> {
+    fn priority(graph: &G, u: Node) -> u64 {
+        graph.in_degree(u) as u64 * graph.out_degree(u) as u64
+    }
+}
+
+impl<G: AdjacencyListIn + GraphEdgeEditing> Selector<G> for MaxDegreeProductSelector<G> {
+    fn new(graph: G) -> Self {
+        let n = graph.len();
+        let mut queue = KeyedPriorityQueue::with_capacity_and_hasher(n, FxBuildHasher::default());
+        for v in graph.vertices() {
+            queue.push(v, Self::priority(&graph, v));
+        }
+        Self { graph, queue }
+    }
+
+    fn graph(&self) -> &G {
+        &self.graph
+    }
+
+    fn graph_mut(&mut self) -> &mut G {
+        &mut self.graph
+    }
+
+    fn remove_best(&mut self) -> Node {
+        let (u, _) = self
+            .queue
+            .pop()
+            .expect("Expected to queue to be non-empty!");
+        let nb: FxHashSet<_> = self
+            .graph
+            .in_neighbors(u)
+            .chain(self.graph.out_neighbors(u))
+            .filter(|&v| v != u)
+            .collect();
+        self.graph.remove_edges_at_node(u);
+        for v in nb {
+            self.queue
+                .set_priority(&v, Self::priority(&self.graph, v))
+                .unwrap_or_else(|_| panic!("Expected to find entry {} in queue!", v));
+        }
+        u
+    }
+
+    fn best(&self) -> Node {
+        *self
+            .queue
+            .peek()
+            .expect("Expected queue to be non-empty!")
+            .0
+    }
+}
+
+/// Tries to put each node of the DFVS back into the graph (in reverse order) and drops it from the
+/// solution if this does not close a cycle. Requires that `dfvs` is a valid DFVS of `graph`.
+pub fn remove_redundant_nodes<G: AdjacencyList>(graph: &G, dfvs: &mut Vec<Node>) {
+    let mut i = dfvs.len();
+    while i > 0 {
+        i -= 1;
+        let u = dfvs[i];
+        if !graph.is_node_on_cycle_after_deleting(u, dfvs.iter().copied()) {
+            dfvs.swap_remove(i);
+        }
+    }
+}
+
+/// Returns a heuristic DFVS by repeatedly deleting the node maximizing the product of in- and
+/// out-degree until the graph becomes acyclic, followed by [`remove_redundant_nodes`].
+/// The result is sorted.
+pub fn greedy_dfvs_with_pruning<G: AdjacencyList>(graph: &G) -> Vec<Node> {
+    let mut working_copy = AdjArrayIn::new(graph.len());
+    for (u, v) in graph.edges_iter() {
+        working_copy.add_edge(u, v);
+    }
+
+    let mut dfvs: Vec<Node> = greedy_dfvs::<MaxDegreeProductSelector<_>, _, _>(working_copy);
+    remove_redundant_nodes(graph, &mut dfvs);
+    dfvs.sort_unstable();
+    dfvs
+}
+
 /// Returns a heuristic solution to the DFVS-Problem as a vector of nodes
 pub fn greedy_dfvs<S: Selector<G>, T: FromIterator<Node>, G: AdjacencyListIn + GraphEdgeEditing>(
     graph: G,
@@ -119,9 +209,12 @@ pub fn greedy_dfvs<S: Selector<G>, T: FromIterator<Node>, G: AdjacencyListIn + G
 
 #[cfg(test)]
 pub mod tests {
-    use crate::graph::adj_array::AdjArrayIn;
-    use crate::graph::{GraphEdgeEditing, Traversal};
-    use crate::heuristics::greedy::{greedy_dfvs, MaxDegreeSelector};
+    use super::*;
+    use crate::bitset::BitSet;
+    use crate::graph::{AdjArray, GraphOrder, InducedSubgraph};
+    use crate::random_models::gnp::generate_gnp;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
 
     #[test]
     fn max_degree() {
@@ -136,4 +229,30 @@ pub mod tests {
         assert_eq!(dfvs.len(), 1);
         assert_eq!(dfvs[0], 3);
     }
+
+    #[test]
+    fn with_pruning() {
+        let graph = AdjArray::from(&[(0, 0), (1, 2), (2, 3), (3, 4), (4, 1), (3, 3)]);
+        assert_eq!(greedy_dfvs_with_pruning(&graph), vec![0, 3]);
+
+        let graph = AdjArray::from(&[(0, 1), (1, 2)]);
+        assert!(greedy_dfvs_with_pruning(&graph).is_empty());
+
+        let mut gen = Pcg64Mcg::seed_from_u64(123);
+        for n in [10, 20, 50, 100] {
+            for avg_deg in [1.0, 3.0, 10.0] {
+                let graph: AdjArray = generate_gnp(&mut gen, n, avg_deg / n as f64);
+                let dfvs = greedy_dfvs_with_pruning(&graph);
+                assert!(dfvs.len() <= graph.len());
+
+                let mask = BitSet::new_all_set_but(graph.len(), dfvs.iter().copied());
+                assert!(graph.vertex_induced(&mask).0.is_acyclic());
+
+                // pruning leaves a minimal solution
+                for &u in &dfvs {
+                    assert!(graph.is_node_on_cycle_after_deleting(u, dfvs.iter().copied()));
+                }
+            }
+        }
+    }
 }
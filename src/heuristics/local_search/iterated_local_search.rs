@@ -0,0 +1,170 @@
+use crate::algorithm::IterativeAlgorithm;
+use crate::bitset::BitSet;
+use crate::graph::node_mapper::Getter;
+use crate::graph::{AdjArrayIn, AdjacencyList, GraphOrder, InducedSubgraph, Node, NodeMapper};
+use crate::heuristics::greedy::{greedy_dfvs_with_pruning, remove_redundant_nodes};
+use crate::heuristics::utils::remove_self_loop_nodes;
+use crate::log::{log_if, Level};
+use rand::Rng;
+use std::time::Duration;
+
+/// Iterated local search: every step perturbs the current DFVS by flipping the membership of a few
+/// random nodes, repairs the result with [`greedy_dfvs_with_pruning`] on the remaining cycles and
+/// makes it minimal with [`remove_redundant_nodes`]. The perturbed solution replaces the current one
+/// if it is not larger. Nodes with self-loops are fixed in the solution and removed beforehand.
+pub struct IteratedLocalSearch<'a, R> {
+    rng: &'a mut R,
+    /// Graph without the nodes carrying self-loops
+    graph: AdjArrayIn,
+    mapper: NodeMapper,
+    self_loops: Vec<Node>,
+    /// Amount of nodes whose membership is flipped in every perturbation
+    kick_size: usize,
+    /// Current DFVS in the ids of `graph`
+    current: Vec<Node>,
+    /// Best DFVS found so far in the ids of the input graph; always valid and sorted
+    best: Vec<Node>,
+    steps: usize,
+}
+
+impl<'a, R: Rng> IteratedLocalSearch<'a, R> {
+    pub fn new<G: AdjacencyList>(graph: &G, rng: &'a mut R, kick_size: usize) -> Self {
+        let (subgraph, mapper, self_loops) = remove_self_loop_nodes(graph);
+
+        let current = greedy_dfvs_with_pruning(&subgraph);
+        let mut result = Self {
+            rng,
+            graph: subgraph,
+            mapper,
+            self_loops,
+            kick_size: kick_size.max(1),
+            current: Vec::new(),
+            best: Vec::new(),
+            steps: 0,
+        };
+        result.best = result.to_input_ids(&current);
+        result.current = current;
+        result
+    }
+
+    /// Number of ILS iterations carried out so far
+    pub fn steps(&self) -> usize {
+        self.steps
+    }
+
+    /// Translates a DFVS of `self.graph` into a sorted DFVS of the input graph
+    fn to_input_ids(&self, dfvs: &[Node]) -> Vec<Node> {
+        let mut result = self.self_loops.clone();
+        result.extend(dfvs.iter().map(|&u| self.mapper.old_id_of(u).unwrap()));
+        result.sort_unstable();
+        result
+    }
+
+    /// Flips the membership of `kick_size` random nodes and returns the (possibly invalid) result
+    fn perturb(&mut self) -> BitSet {
+        let mut in_dfvs = BitSet::new_all_unset_but(self.graph.len(), self.current.iter().copied());
+        for _ in 0..self.kick_size {
+            let u = self.rng.gen_range(0..self.graph.len());
+            in_dfvs.flip_bit(u);
+        }
+        in_dfvs
+    }
+
+    /// Adds nodes to `in_dfvs` until it is a DFVS and removes redundant nodes afterwards
+    fn repair(&self, mut in_dfvs: BitSet) -> Vec<Node> {
+        let mut keep = in_dfvs.clone();
+        keep.not();
+        let (remaining, mapper) = self.graph.vertex_induced(&keep);
+        for u in greedy_dfvs_with_pruning(&remaining) {
+            in_dfvs.set_bit(mapper.old_id_of(u).unwrap() as usize);
+        }
+
+        let mut dfvs: Vec<Node> = in_dfvs.iter().map(|u| u as Node).collect();
+        remove_redundant_nodes(&self.graph, &mut dfvs);
+        dfvs
+    }
+}
+
+impl<'a, R: Rng> IterativeAlgorithm for IteratedLocalSearch<'a, R> {
+    fn execute_step(&mut self) {
+        if !self.graph.is_empty() {
+            let perturbed = self.perturb();
+            let candidate = self.repair(perturbed);
+            if candidate.len() <= self.current.len() {
+                if candidate.len() + self.self_loops.len() < self.best.len() {
+                    self.best = self.to_input_ids(&candidate);
+                }
+                self.current = candidate;
+            }
+        }
+        self.steps += 1;
+        log_if!(Level::Trace, || format!(
+            "ILS step {}: current {}, best {}",
+            self.steps,
+            self.current.len() + self.self_loops.len(),
+            self.best.len()
+        ));
+    }
+
+    fn is_completed(&self) -> bool {
+        self.current.is_empty()
+    }
+
+    fn best_known_solution(&mut self) -> Option<&[Node]> {
+        Some(&self.best)
+    }
+}
+
+/// Runs [`IteratedLocalSearch`] (flipping 3 nodes per perturbation) until `time_budget` is exhausted
+/// and returns the best DFVS found
+pub fn iterated_local_search<G: AdjacencyList, R: Rng>(
+    graph: &G,
+    rng: &mut R,
+    time_budget: Duration,
+) -> Vec<Node> {
+    let mut ils = IteratedLocalSearch::new(graph, rng, 3);
+    ils.run_until_timeout(time_budget);
+    ils.best_known_solution().unwrap().to_vec()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::Traversal;
+    use crate::heuristics::utils::apply_fvs_to_graph;
+    use crate::random_models::gnp::generate_gnp;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
+
+    #[test]
+    fn incumbent_is_monotone_and_valid() {
+        let mut gen_rng = Pcg64Mcg::seed_from_u64(2);
+        for n in [1, 10, 40, 80] {
+            let graph: AdjArrayIn = generate_gnp(&mut gen_rng, n, 0.08);
+            let mut rng = Pcg64Mcg::seed_from_u64(n as u64);
+            let mut ils = IteratedLocalSearch::new(&graph, &mut rng, 3);
+
+            let mut prev_size = graph.len() + 1;
+            for _ in 0..50 {
+                let best = ils.best_known_solution().unwrap().to_vec();
+                assert!(best.len() <= prev_size);
+                assert!(apply_fvs_to_graph(&graph, best.iter().copied()).is_acyclic());
+                prev_size = best.len();
+
+                if ils.is_completed() {
+                    break;
+                }
+                ils.execute_step();
+            }
+        }
+    }
+
+    #[test]
+    fn with_self_loops() {
+        let graph = AdjArrayIn::from(&[(0, 0), (0, 1), (1, 2), (2, 1), (3, 3)]);
+        let mut rng = Pcg64Mcg::seed_from_u64(0);
+        let dfvs = iterated_local_search(&graph, &mut rng, Duration::from_millis(10));
+        assert_eq!(dfvs.len(), 3);
+        assert!(dfvs.contains(&0) && dfvs.contains(&3));
+    }
+}
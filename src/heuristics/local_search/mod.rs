@@ -1,2 +1,3 @@
+pub mod iterated_local_search;
 pub mod sim_anneal;
 pub mod topo;
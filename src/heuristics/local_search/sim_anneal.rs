@@ -1,9 +1,15 @@
+use super::topo::rand_topo_strategy::RandomTopoStrategy;
 use super::topo::topo_config::{TopoConfig, TopoGraph, TopoMoveStrategy};
 use super::topo::topo_local_search::TopoLocalSearch;
+use super::topo::vec_topo_config::VecTopoConfig;
 use crate::algorithm::{IterativeAlgorithm, TerminatingIterativeAlgorithm};
 use crate::bench::io::keyed_buffer::KeyedBuffer;
-use crate::graph::Node;
-use rand::Rng;
+use crate::graph::node_mapper::Getter;
+use crate::graph::{AdjacencyList, GraphOrder, Node};
+use crate::heuristics::greedy::greedy_dfvs_with_pruning;
+use crate::heuristics::utils::remove_self_loop_nodes;
+use rand::{Rng, SeedableRng};
+use rand_pcg::Pcg64Mcg;
 
 /// Implementation of the simulated annealing algorithm that is presented in the
 /// "Applying local search to the feedback vertex set problem" paper by Philippe Galinier et al.
@@ -146,16 +152,61 @@ where
 {
 }
 
+/// Runs [`SimAnneal`] for at most `iters` evaluated moves, starting from the solution of
+/// [`greedy_dfvs_with_pruning`] and with temperature `start_temp`. Nodes with self-loops are always
+/// part of the solution and are removed from the graph beforehand, as the topological
+/// configuration requires a graph without self-loops. The returned DFVS is sorted and never
+/// larger than the greedy one; for a fixed seed of `rng` the result is deterministic.
+pub fn simulated_annealing_dfvs<G, R>(
+    graph: &G,
+    rng: &mut R,
+    iters: usize,
+    start_temp: f64,
+) -> Vec<Node>
+where
+    G: AdjacencyList,
+    R: Rng,
+{
+    let (subgraph, mapper, self_loops) = remove_self_loop_nodes(graph);
+
+    let seed = greedy_dfvs_with_pruning(&subgraph);
+    let mut strategy_rng = Pcg64Mcg::seed_from_u64(rng.gen());
+    let local_search = TopoLocalSearch::new(
+        VecTopoConfig::new_with_fvs(&subgraph, seed),
+        RandomTopoStrategy::new(&mut strategy_rng, 7),
+    );
+
+    // the iteration budget is the only stop condition besides an exhausted local search
+    let stage_evals = subgraph.len().max(1);
+    let mut sim_anneal =
+        SimAnneal::new(local_search, stage_evals, usize::MAX, start_temp, 0.95, rng);
+    for _ in 0..iters {
+        if sim_anneal.is_completed() {
+            break;
+        }
+        sim_anneal.execute_step();
+    }
+
+    let mut dfvs = self_loops;
+    dfvs.extend(
+        sim_anneal
+            .best_known_solution()
+            .unwrap()
+            .iter()
+            .map(|&u| mapper.old_id_of(u).unwrap()),
+    );
+    dfvs.sort_unstable();
+    dfvs
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
     use crate::bench::fvs_bench::test_utils::test_algo_with_pace_graphs;
     use crate::graph::adj_array::AdjArrayIn;
     use crate::graph::{GraphNew, Traversal};
-    use crate::heuristics::local_search::topo::rand_topo_strategy::RandomTopoStrategy;
-    use crate::heuristics::local_search::topo::vec_topo_config::VecTopoConfig;
     use crate::heuristics::utils::apply_fvs_to_graph;
-    use rand::SeedableRng;
+    use crate::random_models::gnp::generate_gnp;
     use rand_pcg::Pcg64;
 
     #[test]
@@ -241,4 +292,45 @@ mod test {
         sim_anneal.run_to_completion();
         assert_eq!(sim_anneal.move_evals_total, 8);
     }
+
+    #[test]
+    fn simulated_annealing_dfvs_finds_optimum() {
+        // two directed triangles sharing node 2 plus a 2-cycle (4, 5) and a self-loop at 6,
+        // so the optimum {2, 4 or 5, 6} has size 3
+        let graph = AdjArrayIn::from(&[
+            (0, 1),
+            (1, 2),
+            (2, 0),
+            (2, 3),
+            (3, 4),
+            (4, 2),
+            (4, 5),
+            (5, 4),
+            (6, 6),
+            (6, 0),
+        ]);
+
+        let mut rng = Pcg64Mcg::seed_from_u64(3);
+        let dfvs = simulated_annealing_dfvs(&graph, &mut rng, 1000, 1.0);
+        assert_eq!(dfvs.len(), 3);
+        assert!(dfvs.contains(&6));
+        assert!(apply_fvs_to_graph(&graph, dfvs).is_acyclic());
+    }
+
+    #[test]
+    fn simulated_annealing_dfvs_is_valid() {
+        let mut gen_rng = Pcg64Mcg::seed_from_u64(1);
+        for n in [1, 10, 30, 60] {
+            let graph: AdjArrayIn = generate_gnp(&mut gen_rng, n, 0.1);
+            let greedy = greedy_dfvs_with_pruning(&graph);
+
+            let mut rng = Pcg64Mcg::seed_from_u64(n as u64);
+            let dfvs = simulated_annealing_dfvs(&graph, &mut rng, 500, 1.0);
+            assert!(dfvs.len() <= greedy.len());
+            assert!(apply_fvs_to_graph(&graph, dfvs.clone()).is_acyclic());
+
+            let mut rng = Pcg64Mcg::seed_from_u64(n as u64);
+            assert_eq!(simulated_annealing_dfvs(&graph, &mut rng, 500, 1.0), dfvs);
+        }
+    }
 }
@@ -2,7 +2,7 @@ use crate::bitset::BitSet;
 use crate::graph::*;
 use log::info;
 use num::Integer;
-use std::collections::HashSet;
+use std::collections::{HashSet, VecDeque};
 use std::time::Duration;
 
 /// Using calculate_lower_bound, the main method of this file, returns both a graph and lower
@@ -293,10 +293,106 @@ where
     }
 }
 
+/// Greedily packs vertex-disjoint directed cycles, always taking a shortest cycle among the nodes
+/// not covered so far. Self-loops are cycles of length 1. Each cycle is returned in traversal
+/// order. Since every DFVS contains a node of each packed cycle, the number of cycles is a lower
+/// bound on the size of a minimum DFVS (see [`cycle_packing_lower_bound`]).
+///
+/// Each round runs a BFS from each remaining node, so the total time is O(k * n * (n + m)) for k
+/// packed cycles.
+pub fn disjoint_cycle_packing<G: AdjacencyList>(graph: &G) -> Vec<Vec<Node>> {
+    let mut covered = BitSet::new(graph.len());
+    let mut packing: Vec<Vec<Node>> = graph
+        .vertices()
+        .filter(|&u| graph.out_neighbors(u).any(|v| v == u))
+        .map(|u| vec![u])
+        .collect();
+    for cycle in &packing {
+        covered.set_bit(cycle[0] as usize);
+    }
+
+    let mut parent = vec![0 as Node; graph.len()];
+    let mut distance = vec![usize::MAX; graph.len()];
+    let mut touched: Vec<Node> = Vec::new();
+    let mut queue: VecDeque<Node> = VecDeque::new();
+
+    loop {
+        let mut best: Option<Vec<Node>> = None;
+
+        for start in graph.vertices() {
+            if covered[start as usize] {
+                continue;
+            }
+
+            distance[start as usize] = 0;
+            touched.push(start);
+            queue.push_back(start);
+
+            'bfs: while let Some(u) = queue.pop_front() {
+                let length = distance[u as usize] + 1;
+                if best.as_ref().is_some_and(|b| length >= b.len()) {
+                    break; // cannot improve on the best cycle found so far
+                }
+
+                for v in graph.out_neighbors(u) {
+                    if covered[v as usize] {
+                        continue;
+                    }
+                    if v == start {
+                        let mut cycle = vec![u];
+                        while *cycle.last().unwrap() != start {
+                            cycle.push(parent[*cycle.last().unwrap() as usize]);
+                        }
+                        cycle.reverse();
+                        best = Some(cycle);
+                        break 'bfs;
+                    }
+                    if distance[v as usize] == usize::MAX {
+                        distance[v as usize] = length;
+                        parent[v as usize] = u;
+                        touched.push(v);
+                        queue.push_back(v);
+                    }
+                }
+            }
+
+            queue.clear();
+            for u in touched.drain(..) {
+                distance[u as usize] = usize::MAX;
+            }
+
+            if best.as_ref().is_some_and(|b| b.len() == 2) {
+                break; // self-loops are already packed, so there is no shorter cycle
+            }
+        }
+
+        match best {
+            Some(cycle) => {
+                for &u in &cycle {
+                    covered.set_bit(u as usize);
+                }
+                packing.push(cycle);
+            }
+            None => return packing,
+        }
+    }
+}
+
+/// Size of the packing computed by [`disjoint_cycle_packing`], which is a lower bound on the size
+/// of a minimum DFVS
+pub fn cycle_packing_lower_bound<G: AdjacencyList>(graph: &G) -> Node {
+    disjoint_cycle_packing(graph).len() as Node
+}
+
 #[cfg(test)]
 pub mod tests {
     use super::*;
+    use crate::exact::branch_and_bound_matrix::branch_and_bound_matrix;
     use crate::graph::generators::GeneratorSubstructures;
+    use crate::random_models::gnp::generate_gnp;
+    use itertools::Itertools;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
 
     // unit tests for search_and_destroy at k=2 aka unmatch and the call and return of unmatch
     fn create_test_graph1() -> AdjArrayIn {
@@ -401,4 +497,55 @@ pub mod tests {
             assert_eq!(lb.lower_bound(), 1);
         }
     }
+
+    #[test]
+    fn disjoint_cycle_packing_triangles() {
+        for k in 0..5 {
+            let mut graph = AdjArrayIn::new(3 * k as usize);
+            for i in 0..k {
+                graph.connect_cycle(3 * i..3 * (i + 1));
+            }
+
+            let mut packing = disjoint_cycle_packing(&graph);
+            assert_eq!(packing.len(), k as usize);
+            assert_eq!(cycle_packing_lower_bound(&graph), k);
+            for cycle in &mut packing {
+                cycle.sort_unstable();
+            }
+            packing.sort();
+            assert_eq!(
+                packing,
+                (0..k)
+                    .map(|i| vec![3 * i, 3 * i + 1, 3 * i + 2])
+                    .collect_vec()
+            );
+        }
+    }
+
+    #[test]
+    fn disjoint_cycle_packing_shortest_first() {
+        // the 2-cycle (1, 2) is packed before the triangle (0, 1, 2) sharing its nodes
+        let graph = AdjArrayIn::from(&[(0, 1), (1, 2), (2, 0), (2, 1), (3, 3), (3, 4), (4, 0)]);
+        assert_eq!(disjoint_cycle_packing(&graph), vec![vec![3], vec![1, 2]]);
+    }
+
+    #[test]
+    fn disjoint_cycle_packing_gnp() {
+        let mut rng = Pcg64Mcg::seed_from_u64(5);
+        for _ in 0..30 {
+            let graph: AdjArrayIn = generate_gnp(&mut rng, 25, 0.08);
+            let packing = disjoint_cycle_packing(&graph);
+
+            let mut covered = BitSet::new(graph.len());
+            for cycle in &packing {
+                for (i, &u) in cycle.iter().enumerate() {
+                    assert!(!covered.set_bit(u as usize));
+                    assert!(graph.has_edge(u, cycle[(i + 1) % cycle.len()]));
+                }
+            }
+
+            let optimum = branch_and_bound_matrix(&graph, None).unwrap();
+            assert!(packing.len() <= optimum.len());
+        }
+    }
 }
@@ -3,7 +3,11 @@ pub mod node_index_set;
 pub mod set_vec;
 
 use crate::bitset::BitSet;
-use crate::graph::{AdjacencyListIn, GraphEdgeEditing, GraphOrder, InducedSubgraph, Node};
+use crate::exact::branch_and_bound_matrix::branch_and_bound_matrix;
+use crate::graph::{
+    is_dfvs, AdjArrayIn, AdjacencyList, AdjacencyListIn, GraphEdgeEditing, GraphNew, GraphOrder,
+    InducedSubgraph, Node, NodeMapper,
+};
 
 /// Creates a subgraph of the passed in graph by removing all nodes of the fvs
 pub fn apply_fvs_to_graph<G, I>(graph: &G, fvs: I) -> G
@@ -16,3 +20,123 @@ where
     let (graph, _node_mapping) = (*graph).vertex_induced(&bit_set);
     graph
 }
+
+/// Copies `graph` into an [`AdjArrayIn`] without the nodes carrying self-loops, which are part of
+/// every DFVS. Returns this subgraph, the mapper translating its node ids into the ones of `graph`
+/// and the (sorted) nodes with self-loops.
+pub fn remove_self_loop_nodes<G: AdjacencyList>(graph: &G) -> (AdjArrayIn, NodeMapper, Vec<Node>) {
+    let mut working_copy = AdjArrayIn::new(graph.len());
+    for (u, v) in graph.edges_iter() {
+        working_copy.add_edge(u, v);
+    }
+
+    let self_loops: Vec<Node> = graph
+        .vertices()
+        .filter(|&u| graph.out_neighbors(u).any(|v| v == u))
+        .collect();
+    let (subgraph, mapper) = working_copy.vertex_induced(&BitSet::new_all_set_but(
+        graph.len(),
+        self_loops.iter().copied(),
+    ));
+
+    (subgraph, mapper, self_loops)
+}
+
+/// Returns true iff `solution` is a DFVS of `graph` that does not remain one if any single node
+/// is removed from it (i.e. it is minimal w.r.t. inclusion, but not necessarily minimum)
+pub fn dfvs_is_minimal<G: AdjacencyList>(graph: &G, solution: &[Node]) -> bool {
+    is_dfvs(graph, solution)
+        && (0..solution.len()).all(|i| {
+            let mut smaller = solution.to_vec();
+            smaller.swap_remove(i);
+            !is_dfvs(graph, &smaller)
+        })
+}
+
+/// Instances with more nodes are considered too large by [`quality_gap`]
+pub const QUALITY_GAP_MAX_NODES: usize = 64;
+
+/// Returns `(heuristic.len(), optimum)` where `optimum` is the size of a minimum DFVS computed by
+/// the branch and bound, or `None` if the graph has more than [`QUALITY_GAP_MAX_NODES`] nodes.
+/// If `heuristic` is a DFVS, its size is used as upper bound for the branch and bound.
+pub fn quality_gap<G: AdjacencyList>(graph: &G, heuristic: &[Node]) -> Option<(usize, usize)> {
+    if graph.len() > QUALITY_GAP_MAX_NODES {
+        return None;
+    }
+
+    let upper_bound = is_dfvs(graph, heuristic).then_some(heuristic.len() as Node);
+    let optimum = branch_and_bound_matrix(graph, upper_bound).unwrap();
+    Some((heuristic.len(), optimum.len()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::graph::node_mapper::Getter;
+    use crate::graph::AdjListMatrix;
+    use crate::heuristics::greedy::greedy_dfvs_with_pruning;
+    use crate::random_models::gnp::generate_gnp;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
+
+    #[test]
+    fn validate_solutions() {
+        // two cycles (0, 1, 2) and (2, 3) sharing node 2 and a self-loop at 4
+        let graph = AdjListMatrix::from(&[(0, 1), (1, 2), (2, 0), (2, 3), (3, 2), (4, 4)]);
+
+        assert!(is_dfvs(&graph, &[2, 4]));
+        assert!(dfvs_is_minimal(&graph, &[2, 4]));
+        assert!(is_dfvs(&graph, &[0, 3, 4]));
+        assert!(dfvs_is_minimal(&graph, &[0, 3, 4]));
+
+        // missing node
+        assert!(!is_dfvs(&graph, &[2]));
+        assert!(!is_dfvs(&graph, &[0, 4]));
+        assert!(!dfvs_is_minimal(&graph, &[0, 4]));
+
+        // valid, but not minimal
+        assert!(is_dfvs(&graph, &[1, 2, 4]));
+        assert!(!dfvs_is_minimal(&graph, &[1, 2, 4]));
+
+        assert!(is_dfvs(&AdjArrayIn::new(3), &[]));
+        assert!(dfvs_is_minimal(&AdjArrayIn::new(3), &[]));
+    }
+
+    #[test]
+    fn self_loop_nodes_removed() {
+        let graph = AdjListMatrix::from(&[(0, 1), (1, 1), (1, 2), (2, 0), (2, 3), (3, 3)]);
+        let (subgraph, mapper, self_loops) = remove_self_loop_nodes(&graph);
+
+        assert_eq!(self_loops, vec![1, 3]);
+        assert_eq!(subgraph.len(), 2);
+        assert_eq!(subgraph.number_of_edges(), 1);
+        let (u, v) = subgraph.edges_iter().next().unwrap();
+        assert_eq!(
+            (mapper.old_id_of(u).unwrap(), mapper.old_id_of(v).unwrap()),
+            (2, 0)
+        );
+    }
+
+    #[test]
+    fn quality_gap_of_greedy() {
+        let mut rng = Pcg64Mcg::seed_from_u64(3);
+        for n in [5, 20, 40] {
+            let graph: AdjArrayIn = generate_gnp(&mut rng, n, 0.1);
+            let heuristic = greedy_dfvs_with_pruning(&graph);
+            let (heuristic_size, optimal_size) = quality_gap(&graph, &heuristic).unwrap();
+
+            assert_eq!(heuristic_size, heuristic.len());
+            assert!(optimal_size <= heuristic_size);
+            assert_eq!(
+                optimal_size,
+                branch_and_bound_matrix(&graph, None).unwrap().len()
+            );
+
+            // an invalid heuristic solution must not be used as upper bound
+            assert_eq!(quality_gap(&graph, &[]), Some((0, optimal_size)));
+        }
+
+        let large = AdjArrayIn::new(QUALITY_GAP_MAX_NODES + 1);
+        assert_eq!(quality_gap(&large, &[]), None);
+    }
+}
@@ -1,15 +1,18 @@
 use crate::graph::*;
+use log::info;
 use std::time::Duration;
 mod crown;
 pub mod flow_based;
 mod redundant_cycles;
 mod single_staged;
+mod solution_mapper;
 mod two_staged;
 
 pub use crown::*;
 pub use flow_based::*;
 pub use redundant_cycles::apply_rule_redundant_cycle;
 pub use single_staged::*;
+pub use solution_mapper::SolutionMapper;
 pub use two_staged::*;
 
 pub trait ReducibleGraph:
@@ -382,6 +385,20 @@ impl<G: ReducibleGraph> PreprocessorReduction<G> {
         apply_rule_4(&mut self.graph, &mut self.in_fvs)
     }
 
+    pub fn apply_rule_trim(&mut self) -> bool {
+        apply_rule_trim(&mut self.graph)
+    }
+
+    pub fn apply_rule_fold_degree_two(&mut self) -> bool {
+        match fold_degree_two(&mut self.graph) {
+            Some(info) => {
+                info.lift(&mut self.in_fvs);
+                true
+            }
+            None => false,
+        }
+    }
+
     pub fn apply_rule_5(&mut self) -> bool {
         apply_rule_5(&mut self.graph, &mut self.in_fvs)
     }
@@ -523,6 +540,152 @@ pub fn apply_rules_exhaustively<G: ReducibleGraph>(
     }
 }
 
+/// Counts how often each rule fired during [`reduce_to_fixpoint`] and by how much the graph shrank.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReductionStats {
+    pub self_loops: usize,
+    pub sink_source: usize,
+    pub degree_two: usize,
+    pub chaining: usize,
+    pub pie: usize,
+    pub di_cliques: usize,
+    pub dome: usize,
+    /// Number of nodes that lost all their incident edges (including the forced ones)
+    pub removed_nodes: usize,
+    pub removed_edges: usize,
+}
+
+impl ReductionStats {
+    /// Total number of successful rule applications
+    pub fn total_applications(&self) -> usize {
+        self.self_loops
+            + self.sink_source
+            + self.degree_two
+            + self.chaining
+            + self.pie
+            + self.di_cliques
+            + self.dome
+    }
+
+    pub fn log(&self) {
+        info!(
+            "Reduction applied rules {} times (self-loops: {}, sink/source: {}, degree-2: {}, \
+             chaining: {}, PIE: {}, di-cliques: {}, DOME: {}) and removed {} nodes and {} edges",
+            self.total_applications(),
+            self.self_loops,
+            self.sink_source,
+            self.degree_two,
+            self.chaining,
+            self.pie,
+            self.di_cliques,
+            self.dome,
+            self.removed_nodes,
+            self.removed_edges
+        );
+    }
+}
+
+/// Applies the cheap rules (self-loops, sources/sinks, degree-2 folding, rule 4, PIE, di-cliques
+/// and DOME) until none of them fires anymore and logs the resulting statistics. Returns the
+/// nodes forced into the solution; together with a DFVS of the reduced graph they form a DFVS of
+/// the original graph, which is minimum if the DFVS of the reduced graph is.
+pub fn reduce_to_fixpoint<G: ReducibleGraph>(graph: &mut G) -> (Vec<Node>, ReductionStats) {
+    let count_active = |graph: &G| {
+        graph
+            .vertices()
+            .filter(|&u| graph.total_degree(u) > 0)
+            .count()
+    };
+    let nodes_before = count_active(graph);
+    let edges_before = graph.number_of_edges();
+
+    let mut fvs = Vec::new();
+    let mut stats = ReductionStats::default();
+    loop {
+        if apply_rule_1(graph, &mut fvs) {
+            stats.self_loops += 1;
+            continue;
+        }
+        if apply_rule_3(graph) {
+            stats.sink_source += 1;
+            continue;
+        }
+        if let Some(info) = fold_degree_two(graph) {
+            info.lift(&mut fvs);
+            stats.degree_two += 1;
+            continue;
+        }
+        if apply_rule_4(graph, &mut fvs) {
+            stats.chaining += 1;
+            continue;
+        }
+        if apply_rule_pie(graph) {
+            stats.pie += 1;
+            continue;
+        }
+        if apply_rule_di_cliques(graph, &mut fvs) {
+            stats.di_cliques += 1;
+            continue;
+        }
+        if apply_rule_dome(graph) {
+            stats.dome += 1;
+            continue;
+        }
+        break;
+    }
+
+    stats.removed_nodes = nodes_before - count_active(graph);
+    stats.removed_edges = edges_before - graph.number_of_edges();
+    stats.log();
+
+    (fvs, stats)
+}
+
+/// Result of [`kernelize`]
+#[derive(Clone)]
+pub struct KernelReport<G> {
+    /// The reduced graph without nodes that lost all their edges, relabelled to `0..n`
+    pub kernel: G,
+    /// Nodes of the original graph forced into the solution
+    pub forced: Vec<Node>,
+    /// Translates a DFVS of `kernel` into a DFVS of the original graph
+    pub mapper: SolutionMapper,
+    pub stats: ReductionStats,
+    /// Number of nodes and edges of the original graph
+    pub before: (Node, usize),
+    /// Number of nodes and edges of the kernel
+    pub after: (Node, usize),
+}
+
+impl<G> KernelReport<G> {
+    /// Translates a DFVS of the kernel into a sorted DFVS of the original graph
+    pub fn lift(&self, kernel_solution: &[Node]) -> Vec<Node> {
+        self.mapper.lift(kernel_solution)
+    }
+}
+
+/// Reduces a copy of `graph` with [`reduce_to_fixpoint`], drops the nodes without edges and
+/// returns the resulting kernel together with everything needed to lift its solutions.
+pub fn kernelize<G: ReducibleGraph>(graph: &G) -> KernelReport<G> {
+    let mut reduced = graph.clone();
+    let (forced, stats) = reduce_to_fixpoint(&mut reduced);
+    let (kernel, node_mapper) = reduced.remove_disconnected_verts();
+
+    let mut mapper = SolutionMapper::new();
+    mapper.add_forced(forced.iter().copied());
+    mapper.add_relabelling(node_mapper);
+
+    let after = (kernel.number_of_nodes(), kernel.number_of_edges());
+    KernelReport {
+        kernel,
+        forced,
+        mapper,
+        stats,
+        before: (graph.number_of_nodes(), graph.number_of_edges()),
+        after,
+    }
+}
+
 /// Repeatedly calls `pred` until it returns `false` for the first time. Returns true if `pred`
 /// returned true at least once
 fn repeat_while<F: FnMut() -> bool>(mut pred: F) -> bool {
@@ -541,7 +704,10 @@ mod tests {
     use super::*;
     use crate::bitset::BitSet;
     use crate::exact::branch_and_bound_matrix::branch_and_bound_matrix;
+    use crate::random_models::gnp::generate_gnp;
     use glob::glob;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
     use std::fs::File;
     use std::io::BufReader;
     use std::str::FromStr;
@@ -592,6 +758,101 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn reduce_to_fixpoint_preserves_optimum() {
+        // self-loop at 0, a triangle 1 -> 2 -> 3 -> 1 attached via 0, and a di-clique {4, 5, 6}
+        let org_graph = AdjArrayUndir::from(&[
+            (0, 0),
+            (0, 1),
+            (1, 2),
+            (2, 3),
+            (3, 1),
+            (3, 4),
+            (4, 5),
+            (5, 4),
+            (4, 6),
+            (6, 4),
+            (5, 6),
+            (6, 5),
+        ]);
+
+        let mut graph = org_graph.clone();
+        let (fvs, stats) = reduce_to_fixpoint(&mut graph);
+        assert!(stats.self_loops > 0);
+        assert!(stats.total_applications() > stats.self_loops);
+        assert_eq!(stats.removed_edges, org_graph.number_of_edges());
+        assert_eq!(stats.removed_nodes, 7);
+        assert_eq!(graph.number_of_edges(), 0);
+        assert_eq!(
+            fvs.len(),
+            branch_and_bound_matrix(&org_graph, None).unwrap().len()
+        );
+
+        // a second pass has nothing left to do
+        let (fvs, stats) = reduce_to_fixpoint(&mut graph);
+        assert!(fvs.is_empty());
+        assert_eq!(stats, ReductionStats::default());
+    }
+
+    #[test]
+    fn kernelize_report() {
+        // self-loop at 3, which also breaks the triangle (1, 2, 3), and a tail 4 -> 0
+        let mut edges = vec![(1, 2), (2, 3), (3, 1), (3, 3), (4, 0)];
+        // bidirected 5-cycle on the nodes 5..10 (with chords towards increasing ids) forms the kernel
+        for u in 5..10 {
+            for v in 5..u {
+                edges.push((v, u));
+                if v + 1 == u || (v, u) == (5, 9) {
+                    edges.push((u, v));
+                }
+            }
+        }
+        let org_graph = AdjArrayUndir::from(&edges);
+
+        let report = kernelize(&org_graph);
+        assert_eq!(report.before, (10, 5 + 15));
+        assert!(report.after.0 < report.before.0);
+        assert!(report.after.1 < report.before.1);
+        assert_eq!(report.after.0, 5);
+        assert_eq!(report.after.0, report.kernel.number_of_nodes());
+        assert_eq!(report.forced, vec![3]);
+
+        let optimum = branch_and_bound_matrix(&org_graph, None).unwrap();
+        let kernel_optimum = branch_and_bound_matrix(&report.kernel, None).unwrap();
+        let solution = report.lift(&kernel_optimum);
+        assert_eq!(solution.len(), optimum.len());
+        assert!(org_graph
+            .vertex_induced(&BitSet::new_all_set_but(
+                org_graph.len(),
+                solution.iter().copied()
+            ))
+            .0
+            .is_acyclic());
+    }
+
+    #[test]
+    fn reduce_to_fixpoint_random() {
+        let mut rng = Pcg64Mcg::seed_from_u64(7);
+        for _ in 0..20 {
+            let org_graph: AdjArrayUndir = generate_gnp(&mut rng, 30, 0.06);
+            let mut graph = org_graph.clone();
+            let (mut fvs, stats) = reduce_to_fixpoint(&mut graph);
+            assert_eq!(
+                stats.removed_edges,
+                org_graph.number_of_edges() - graph.number_of_edges()
+            );
+
+            let kernel_fvs = branch_and_bound_matrix(&graph, None).unwrap();
+            let optimum = branch_and_bound_matrix(&org_graph, None).unwrap();
+            fvs.extend(kernel_fvs);
+            assert_eq!(fvs.len(), optimum.len());
+            assert!(org_graph
+                .vertex_induced(&BitSet::new_all_set_but(org_graph.len(), fvs))
+                .0
+                .is_acyclic());
+        }
+    }
+
     pub(super) fn for_each_stress_graph<F: FnMut(&String, &AdjArrayUndir) -> ()>(mut callback: F) {
         glob("data/stress_kernels/*_n*_m*_0[01]*.metis")
             .unwrap()
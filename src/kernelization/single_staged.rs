@@ -18,6 +18,14 @@ pub fn apply_rule_1<G: ReducibleGraph>(graph: &mut G, fvs: &mut Vec<Node>) -> bo
     applied
 }
 
+/// Same as [`apply_rule_1`], but returns the nodes with self-loops (in increasing order), which
+/// belong to every DFVS. Afterwards, they have no incident edges in `graph`.
+pub fn force_self_loops<G: ReducibleGraph>(graph: &mut G) -> Vec<Node> {
+    let mut forced = Vec::new();
+    apply_rule_1(graph, &mut forced);
+    forced
+}
+
 /// rule 3 sink/source nodes
 ///
 /// returns true if rule got applied at least once, false if not at all
@@ -35,6 +43,25 @@ pub fn apply_rule_3<G: ReducibleGraph>(graph: &mut G) -> bool {
     })
 }
 
+/// Same as [`apply_rule_3`], but returns the peeled sources and sinks (in increasing order).
+/// These nodes lie on no cycle and hence belong to no minimal DFVS. Afterwards, they have no
+/// incident edges in `graph`.
+pub fn peel_acyclic_fringe<G: ReducibleGraph>(graph: &mut G) -> Vec<Node> {
+    let mut peeled = Vec::new();
+    repeat_while(|| {
+        let len_before = peeled.len();
+        for u in graph.vertices_range() {
+            if (graph.in_degree(u) == 0) != (graph.out_degree(u) == 0) {
+                graph.remove_edges_at_node(u);
+                peeled.push(u);
+            }
+        }
+        peeled.len() > len_before
+    });
+    peeled.sort_unstable();
+    peeled
+}
+
 /// rule 4 chaining nodes with deleting self loop
 ///
 /// returns true if rule got applied at least once, false if not at all
@@ -54,6 +81,86 @@ pub fn apply_rule_4<G: ReducibleGraph>(graph: &mut G, fvs: &mut Vec<Node>) -> bo
     })
 }
 
+/// Bookkeeping of [`fold_degree_two`] that is required to lift a solution of the folded graph
+/// to a solution of the original graph.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ReductionInfo {
+    /// Triples (v, a, b) in the order in which v was bypassed by the edge (a, b)
+    pub folded: Vec<(Node, Node, Node)>,
+    /// Nodes that got a self-loop by a fold (i.e. a == b) and hence belong to every DFVS.
+    /// Their edges are removed from the folded graph.
+    pub forced: Vec<Node>,
+}
+
+impl ReductionInfo {
+    /// Turns a DFVS of the folded graph into a DFVS of the original graph. Bypassed nodes are
+    /// never needed, since every cycle through v also passes through a and b.
+    pub fn lift(&self, dfvs: &mut Vec<Node>) {
+        dfvs.extend(&self.forced);
+    }
+}
+
+/// Degree-2 folding: a node v without self-loop having exactly one in-neighbor a and one
+/// out-neighbor b is bypassed by the edge (a, b). This is a special case of rule 4, which
+/// additionally records the folds.
+///
+/// returns None if the rule was not applied at all
+pub fn fold_degree_two<G: ReducibleGraph>(graph: &mut G) -> Option<ReductionInfo> {
+    let mut info = ReductionInfo::default();
+    repeat_while(|| {
+        let mut applied = false;
+        for v in graph.vertices_range() {
+            if graph.in_degree(v) != 1 || graph.out_degree(v) != 1 || graph.has_edge(v, v) {
+                continue;
+            }
+
+            let a = graph.in_neighbors(v).next().unwrap();
+            let b = graph.out_neighbors(v).next().unwrap();
+            let loops = graph.contract_node(v);
+            debug_assert!(loops.iter().all(|&u| u == a && a == b));
+            graph.remove_edges_of_nodes(&loops);
+
+            info.folded.push((v, a, b));
+            info.forced.extend(loops);
+            applied = true;
+        }
+        applied
+    });
+
+    if info.folded.is_empty() {
+        None
+    } else {
+        Some(info)
+    }
+}
+
+/// Trimming of nodes that cannot lie on a cycle: combines rule 3 (sinks and sources) with the
+/// bypassing of nodes having exactly one in-neighbor a and one out-neighbor b != a until a fixpoint
+/// is reached. In contrast to rule 4, no self-loops are created, so no node is added to the DFVS.
+///
+/// returns true if rule got applied at least once, false if not at all
+pub fn apply_rule_trim<G: ReducibleGraph>(graph: &mut G) -> bool {
+    repeat_while(|| {
+        let mut applied = apply_rule_3(graph);
+        for v in graph.vertices_range() {
+            if graph.in_degree(v) != 1 || graph.out_degree(v) != 1 || graph.has_edge(v, v) {
+                continue;
+            }
+
+            let a = graph.in_neighbors(v).next().unwrap();
+            let b = graph.out_neighbors(v).next().unwrap();
+            if a == b {
+                continue;
+            }
+
+            let loops = graph.contract_node(v);
+            debug_assert!(loops.is_empty());
+            applied = true;
+        }
+        applied
+    })
+}
+
 /// rule 4 chaining nodes with deleting self loop
 ///
 /// returns true if rule got applied at least once, false if not at all
@@ -545,6 +652,8 @@ pub(super) fn graph_without_pie<G: ReducibleGraph>(graph: &G) -> G {
 mod tests {
     use super::super::tests::*;
     use super::*;
+    use crate::bitset::BitSet;
+    use crate::exact::branch_and_bound_matrix::branch_and_bound_matrix;
 
     #[test]
     fn rule_1() {
@@ -555,6 +664,16 @@ mod tests {
         assert_eq!(test_pre_process.in_fvs.len(), 2);
     }
 
+    #[test]
+    fn self_loops_forced() {
+        let mut graph = AdjArrayUndir::from(&[(0, 0), (1, 2), (2, 3), (3, 4), (4, 1), (3, 3)]);
+        assert_eq!(force_self_loops(&mut graph), vec![0, 3]);
+        assert_eq!(graph.edges_vec(), vec![(1, 2), (4, 1)]);
+        assert!(graph.is_acyclic());
+
+        assert!(force_self_loops(&mut graph).is_empty());
+    }
+
     #[test]
     fn rule_3() {
         let mut test_pre_process = create_test_pre_processor();
@@ -563,6 +682,42 @@ mod tests {
         assert_eq!(test_pre_process.graph.out_degree(5), 0);
     }
 
+    #[test]
+    fn peel_tail_of_cycle() {
+        // cycle (0, 1, 2) with the tails 2 -> 3 -> ... -> 9 and 12 -> 11 -> 10 -> 0
+        let mut edges = vec![(0, 1), (1, 2), (2, 0), (12, 11), (11, 10), (10, 0)];
+        edges.extend((2..9).map(|u| (u, u + 1)));
+        let mut graph = AdjArrayUndir::from(&edges);
+
+        let peeled = peel_acyclic_fringe(&mut graph);
+        assert_eq!(peeled, (3..13).collect_vec());
+        assert_eq!(graph.edges_vec(), vec![(0, 1), (1, 2), (2, 0)]);
+        assert!(peel_acyclic_fringe(&mut graph).is_empty());
+    }
+
+    #[test]
+    fn rule_trim() {
+        let tree = AdjArrayUndir::from(&[(0, 1), (1, 2), (1, 3), (1, 4), (3, 5), (3, 6)]);
+        let mut test_pre_process = PreprocessorReduction::from(tree);
+        assert!(test_pre_process.apply_rule_trim());
+        assert_eq!(test_pre_process.graph.number_of_edges(), 0);
+        assert!(test_pre_process.in_fvs.is_empty());
+        assert!(!test_pre_process.apply_rule_trim());
+
+        // the 2-cycle (0, 1) survives while the path 1 -> 2 -> 3 -> 0 is bypassed
+        let graph = AdjArrayUndir::from(&[(0, 1), (1, 0), (1, 2), (2, 3), (3, 0), (3, 4), (5, 3)]);
+        let mut test_pre_process = PreprocessorReduction::from(graph.clone());
+        assert!(test_pre_process.apply_rule_trim());
+        assert!(test_pre_process.in_fvs.is_empty());
+        assert_eq!(test_pre_process.graph.edges_vec(), vec![(0, 1), (1, 0)]);
+        assert_eq!(
+            branch_and_bound_matrix(&test_pre_process.graph, None)
+                .unwrap()
+                .len(),
+            branch_and_bound_matrix(&graph, None).unwrap().len()
+        );
+    }
+
     #[test]
     fn rule_4_neighbor_is_neighbor() {
         let mut test_pre_process = {
@@ -619,6 +774,62 @@ mod tests {
         assert_eq!(test_pre_process.graph.number_of_edges(), 10);
     }
 
+    #[test]
+    fn fold_degree_two_long_path() {
+        // the 2-cycle {0, 1} is closed into a long cycle by the induced path 1 -> 2 -> ... -> 99 -> 0
+        let mut edges = vec![(0, 1), (1, 0), (1, 2), (99, 0)];
+        edges.extend((2..99).map(|u| (u, u + 1)));
+        let org_graph = AdjArrayUndir::from(&edges);
+
+        let mut graph = org_graph.clone();
+        let info = fold_degree_two(&mut graph).unwrap();
+        assert_eq!(graph.number_of_edges(), 0);
+        assert_eq!(info.folded.len(), 99);
+        assert_eq!(info.forced.len(), 1);
+
+        let mut dfvs = Vec::new();
+        info.lift(&mut dfvs);
+        assert!(org_graph
+            .vertex_induced(&BitSet::new_all_set_but(org_graph.len(), dfvs))
+            .0
+            .is_acyclic());
+
+        assert!(fold_degree_two(&mut graph).is_none());
+    }
+
+    #[test]
+    fn fold_degree_two_lift() {
+        // node 3 is bypassed by (2, 0) and folding node 4 creates a self-loop at 1
+        let org_graph = AdjArrayUndir::from(&[
+            (0, 1),
+            (1, 0),
+            (1, 2),
+            (2, 3),
+            (3, 0),
+            (2, 1),
+            (4, 1),
+            (1, 4),
+        ]);
+
+        let mut graph = org_graph.clone();
+        let info = fold_degree_two(&mut graph).unwrap();
+        assert_eq!(info.folded[0], (3, 2, 0));
+        assert!(graph.has_edge(2, 0));
+        assert_eq!(graph.total_degree(3), 0);
+        assert_eq!(info.forced, vec![1]);
+
+        let mut dfvs = branch_and_bound_matrix(&graph, None).unwrap();
+        info.lift(&mut dfvs);
+        assert_eq!(
+            dfvs.len(),
+            branch_and_bound_matrix(&org_graph, None).unwrap().len()
+        );
+        assert!(org_graph
+            .vertex_induced(&BitSet::new_all_set_but(org_graph.len(), dfvs))
+            .0
+            .is_acyclic());
+    }
+
     #[test]
     fn pie_reduction() {
         let graph = AdjArrayUndir::from(&[
@@ -667,6 +878,49 @@ mod tests {
         assert_eq!(test_pre_process.graph.edges_vec().len(), 6);
     }
 
+    #[test]
+    fn pie_reduction_keeps_two_cycles() {
+        // directed triangle 2 -> 3 -> 4 -> 2 with a dangling edge (4, 0) into the 2-cycle {0, 1}
+        let graph = AdjArrayUndir::from(&[(0, 1), (1, 0), (2, 3), (3, 4), (4, 2), (4, 0)]);
+
+        let mut test_pre_process = PreprocessorReduction::from(graph);
+        assert!(test_pre_process.apply_rule_pie());
+        assert_eq!(
+            test_pre_process.graph.edges_vec(),
+            vec![(0, 1), (1, 0), (2, 3), (3, 4), (4, 2)]
+        );
+        assert!(test_pre_process.in_fvs.is_empty());
+
+        // the rule reached its fixpoint
+        assert!(!test_pre_process.apply_rule_pie());
+        assert_eq!(test_pre_process.graph.number_of_edges(), 5);
+    }
+
+    #[test]
+    fn di_cliques_triangle() {
+        // 0, 1, 2 are mutually connected by 2-cycles
+        let triangle = [(0, 1), (1, 0), (1, 2), (2, 1), (0, 2), (2, 0)];
+        let mut test_pre_process = PreprocessorReduction::from(AdjArrayUndir::from(&triangle));
+        assert!(test_pre_process.apply_rule_di_cliques());
+        assert_eq!(test_pre_process.in_fvs.len(), 2);
+        assert_eq!(test_pre_process.graph.number_of_edges(), 0);
+
+        // additionally, 0 lies on the directed cycle 0 -> 3 -> 4 -> 0; node 1 only has neighbors
+        // in the clique, so deleting the other two nodes {0, 2} is safe
+        let mut edges = triangle.to_vec();
+        edges.extend([(0, 3), (3, 4), (4, 0)]);
+        let graph = AdjArrayUndir::from(&edges);
+        let mut test_pre_process = PreprocessorReduction::from(graph.clone());
+        assert!(test_pre_process.apply_rule_di_cliques());
+        let mut fvs = test_pre_process.in_fvs.clone();
+        fvs.sort_unstable();
+        assert_eq!(fvs, vec![0, 2]);
+        assert_eq!(
+            fvs.len(),
+            branch_and_bound_matrix(&graph, None).unwrap().len()
+        );
+    }
+
     #[test]
     fn dome_reduction() {
         let graph = AdjArrayUndir::from(&[
@@ -692,6 +946,37 @@ mod tests {
         assert_eq!(test_pre_process.graph.edges_vec().len(), 9);
     }
 
+    #[test]
+    fn dome_single_dominated_edge() {
+        let edges = [
+            (0, 2),
+            (1, 0),
+            (1, 2),
+            (1, 4),
+            (2, 1),
+            (2, 3),
+            (2, 4),
+            (3, 2),
+            (4, 0),
+            (4, 5),
+            (5, 1),
+        ];
+        let graph = AdjArrayUndir::from(&edges);
+        let mut test_pre_process = PreprocessorReduction::from(graph.clone());
+
+        // (1, 0) is dominated as the only non-2-cycle successor of 0 (namely 2) is a successor of 1
+        assert!(test_pre_process.apply_rule_dome());
+        let expected: Vec<_> = edges.iter().copied().filter(|&e| e != (1, 0)).collect();
+        assert_eq!(test_pre_process.graph.edges_vec(), expected);
+
+        assert_eq!(
+            branch_and_bound_matrix(&test_pre_process.graph, None)
+                .unwrap()
+                .len(),
+            branch_and_bound_matrix(&graph, None).unwrap().len()
+        );
+    }
+
     fn create_test_pre_processor() -> PreprocessorReduction<AdjArrayUndir> {
         let graph = AdjArrayUndir::from(&[
             (0, 1),
@@ -762,6 +1047,17 @@ mod tests {
         stress_test_kernel(|graph, fvs, _| Some(apply_rule_domn(graph, fvs)));
     }
 
+    #[test]
+    fn stress_fold_degree_two() {
+        stress_test_kernel(|graph, fvs, _| {
+            let info = fold_degree_two(graph);
+            if let Some(info) = &info {
+                info.lift(fvs);
+            }
+            Some(info.is_some())
+        });
+    }
+
     #[test]
     fn stress_pie() {
         stress_test_kernel(|graph, _, _| Some(apply_rule_pie(graph)));
@@ -0,0 +1,127 @@
+use super::ReductionInfo;
+use crate::graph::node_mapper::Getter;
+use crate::graph::*;
+
+#[derive(Clone)]
+enum LiftStep {
+    /// Nodes (in the ids valid at the time of recording) that belong to the solution
+    Force(Vec<Node>),
+    /// Maps the ids of the graph before the relabelling to the ids after it
+    Relabel(NodeMapper),
+}
+
+/// Records the inverse operations of a sequence of reductions in order to translate a DFVS of the
+/// final (reduced and possibly relabelled) graph into a DFVS of the original graph.
+///
+/// Operations are recorded in the order they happen to the graph; [`SolutionMapper::lift`] undoes
+/// them in reverse order.
+#[derive(Clone, Default)]
+pub struct SolutionMapper {
+    steps: Vec<LiftStep>,
+}
+
+impl SolutionMapper {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records nodes of the current graph that were forced into the solution
+    pub fn add_forced<I: IntoIterator<Item = Node>>(&mut self, nodes: I) {
+        let nodes: Vec<Node> = nodes.into_iter().collect();
+        if !nodes.is_empty() {
+            self.steps.push(LiftStep::Force(nodes));
+        }
+    }
+
+    /// Records the folds carried out by [`super::fold_degree_two`]
+    pub fn add_reduction_info(&mut self, info: &ReductionInfo) {
+        self.add_forced(info.forced.iter().copied());
+    }
+
+    /// Records that the current graph was replaced by a graph with new ids, e.g. as obtained by
+    /// [`InducedSubgraph::vertex_induced`]. `mapper` maps the current ids to the new ones.
+    pub fn add_relabelling(&mut self, mapper: NodeMapper) {
+        self.steps.push(LiftStep::Relabel(mapper));
+    }
+
+    /// Returns true if no operation was recorded, i.e. [`SolutionMapper::lift`] is the identity
+    pub fn is_empty(&self) -> bool {
+        self.steps.is_empty()
+    }
+
+    /// Translates a DFVS of the final graph into a sorted DFVS of the original graph.
+    /// ** Panics if `reduced_solution` contains a node unknown to a recorded relabelling **
+    pub fn lift(&self, reduced_solution: &[Node]) -> Vec<Node> {
+        let mut solution = reduced_solution.to_vec();
+        for step in self.steps.iter().rev() {
+            match step {
+                LiftStep::Force(nodes) => solution.extend(nodes),
+                LiftStep::Relabel(mapper) => {
+                    for u in solution.iter_mut() {
+                        *u = mapper
+                            .old_id_of(*u)
+                            .expect("Node of solution is unknown to the relabelling");
+                    }
+                }
+            }
+        }
+        solution.sort_unstable();
+        solution
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::super::*;
+    use super::*;
+    use crate::algorithm::TerminatingIterativeAlgorithm;
+    use crate::bitset::BitSet;
+    use crate::exact::branch_and_bound_matrix::branch_and_bound_matrix;
+    use crate::exact::BranchAndBound;
+    use crate::random_models::gnp::generate_gnp;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
+
+    #[test]
+    fn relabel_and_force() {
+        let mut mapper = SolutionMapper::new();
+        assert!(mapper.is_empty());
+        mapper.add_forced(vec![0]);
+        // keep the nodes 2, 4, 5 of the original graph as 0, 1, 2
+        mapper.add_relabelling(NodeMapper::from_sequence(&[(2, 0), (4, 1), (5, 2)]));
+        mapper.add_forced(vec![1]);
+        assert_eq!(mapper.lift(&[2]), vec![0, 4, 5]);
+        assert_eq!(mapper.lift(&[]), vec![0, 4]);
+    }
+
+    #[test]
+    fn full_pipeline() {
+        let mut rng = Pcg64Mcg::seed_from_u64(11);
+        for _ in 0..20 {
+            let org_graph: AdjArrayUndir = generate_gnp(&mut rng, 40, 0.06);
+            let mut mapper = SolutionMapper::new();
+
+            let mut graph = org_graph.clone();
+            let (forced, _) = reduce_to_fixpoint(&mut graph);
+            mapper.add_forced(forced);
+
+            let (kernel, node_mapper) = graph.remove_disconnected_verts();
+            mapper.add_relabelling(node_mapper);
+
+            let kernel_solution = BranchAndBound::new(kernel).run_to_completion().unwrap();
+            let solution = mapper.lift(&kernel_solution);
+
+            assert!(org_graph
+                .vertex_induced(&BitSet::new_all_set_but(
+                    org_graph.len(),
+                    solution.iter().copied()
+                ))
+                .0
+                .is_acyclic());
+            assert_eq!(
+                solution.len(),
+                branch_and_bound_matrix(&org_graph, None).unwrap().len()
+            );
+        }
+    }
+}
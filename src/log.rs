@@ -1,11 +1,74 @@
 use env_logger::{Builder, Env};
 use log::LevelFilter;
 use std::io::Write;
+use std::sync::atomic::{AtomicU8, Ordering};
 use std::sync::Arc;
 use std::time::Instant;
 
+/// Verbosity levels of [`log_if`]; a message is emitted iff its level does not exceed the
+/// verbosity set via [`set_verbosity`]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+#[repr(u8)]
+pub enum Level {
+    Error = 0,
+    Info = 1,
+    Debug = 2,
+    Trace = 3,
+}
+
+impl From<Level> for log::Level {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::Error => log::Level::Error,
+            Level::Info => log::Level::Info,
+            Level::Debug => log::Level::Debug,
+            Level::Trace => log::Level::Trace,
+        }
+    }
+}
+
+static VERBOSITY: AtomicU8 = AtomicU8::new(Level::Info as u8);
+
+/// Sets the most verbose level emitted by [`log_if`]. The default is [`Level::Info`].
+pub fn set_verbosity(level: Level) {
+    VERBOSITY.store(level as u8, Ordering::Relaxed);
+}
+
+/// Returns true iff messages of the given level are emitted by [`log_if`]
+#[inline]
+pub fn is_enabled(level: Level) -> bool {
+    level as u8 <= VERBOSITY.load(Ordering::Relaxed)
+}
+
+/// Forwards the message to the logger installed for the `log` crate (e.g. via
+/// [`build_pace_logger_for_level`]); use [`log_if`] instead of calling this directly.
+pub fn emit(level: Level, message: &str) {
+    log::log!(level.into(), "{}", message);
+}
+
+/// Evaluates the closure producing the message only if `level` is enabled (see [`set_verbosity`]).
+/// Disabled messages only cost a relaxed atomic load, so `Trace` messages may be used in hot loops.
+///
+/// # Example
+/// ```
+/// use dfvs::log::Level;
+/// dfvs::log_if!(Level::Trace, || format!("explored {} nodes", 42));
+/// ```
+#[macro_export]
+macro_rules! log_if {
+    ($level:expr, $message:expr) => {{
+        let level = $level;
+        if $crate::log::is_enabled(level) {
+            $crate::log::emit(level, &($message)());
+        }
+    }};
+}
+
+pub use log_if;
+
 /// Builds the logger using the environment variable 'RUST_LOG' to determine the log level. Uses the
-/// passed in `level` if the environment variable is not set.
+/// passed in `level` if the environment variable is not set. The verbosity of [`log_if`] is set to
+/// the resulting level (see [`set_verbosity`]).
 pub fn build_pace_logger_for_level(level: LevelFilter) {
     let start_time = Arc::new(Instant::now());
 
@@ -24,6 +87,9 @@ pub fn build_pace_logger_for_level(level: LevelFilter) {
             )
         })
         .init();
+
+    // `log_if` follows the effective level, which may have been overridden via 'RUST_LOG'
+    set_verbosity(verbosity_from_level(log::max_level()));
 }
 
 /// Builds the logger using the environment variable 'RUST_LOG' to determine the log level. If the
@@ -39,6 +105,17 @@ fn level_from_verbosity(default_level: LevelFilter, verbosity: usize) -> LevelFi
     try_level_from_usize(default_level + verbosity).unwrap_or(LevelFilter::Trace)
 }
 
+/// The most verbose [`Level`] passing the filter; [`Level::Error`] also stands in for `Off` and
+/// `Warn`, as the logger filters these messages anyway
+fn verbosity_from_level(level: LevelFilter) -> Level {
+    match level {
+        LevelFilter::Off | LevelFilter::Error | LevelFilter::Warn => Level::Error,
+        LevelFilter::Info => Level::Info,
+        LevelFilter::Debug => Level::Debug,
+        LevelFilter::Trace => Level::Trace,
+    }
+}
+
 fn usize_from_level(value: LevelFilter) -> usize {
     match value {
         LevelFilter::Off => 0,
@@ -62,6 +139,82 @@ fn try_level_from_usize(value: usize) -> Option<LevelFilter> {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use log::{Metadata, Record};
+    use std::sync::Mutex;
+
+    /// Captures all messages emitted via the `log` crate
+    struct TestSink {
+        messages: Mutex<Vec<String>>,
+    }
+
+    impl log::Log for TestSink {
+        fn enabled(&self, _: &Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &Record) {
+            self.messages
+                .lock()
+                .unwrap()
+                .push(format!("{}", record.args()));
+        }
+
+        fn flush(&self) {}
+    }
+
+    static SINK: TestSink = TestSink {
+        messages: Mutex::new(Vec::new()),
+    };
+
+    #[test]
+    fn log_if() {
+        log::set_logger(&SINK).unwrap();
+        log::set_max_level(LevelFilter::Trace);
+
+        let mut evaluated = Vec::new();
+        for verbosity in [Level::Error, Level::Debug, Level::Trace] {
+            set_verbosity(verbosity);
+            for level in [Level::Error, Level::Info, Level::Debug, Level::Trace] {
+                log_if!(level, || {
+                    evaluated.push((verbosity, level));
+                    format!("log_if test: {:?} at {:?}", level, verbosity)
+                });
+            }
+        }
+        set_verbosity(Level::Info);
+
+        let expected = vec![
+            (Level::Error, Level::Error),
+            (Level::Debug, Level::Error),
+            (Level::Debug, Level::Info),
+            (Level::Debug, Level::Debug),
+            (Level::Trace, Level::Error),
+            (Level::Trace, Level::Info),
+            (Level::Trace, Level::Debug),
+            (Level::Trace, Level::Trace),
+        ];
+        assert_eq!(evaluated, expected);
+
+        // other tests may log concurrently, so we only consider our own messages
+        let messages: Vec<String> = SINK
+            .messages
+            .lock()
+            .unwrap()
+            .iter()
+            .filter(|m| m.starts_with("log_if test"))
+            .cloned()
+            .collect();
+        let expected: Vec<String> = expected
+            .iter()
+            .map(|(verbosity, level)| format!("log_if test: {:?} at {:?}", level, verbosity))
+            .collect();
+        assert_eq!(messages, expected);
+    }
+}
+
 #[cfg(feature = "test-case")]
 #[cfg(test)]
 mod test_cases {
@@ -76,4 +229,12 @@ mod test_cases {
     fn test_level_from_verbosity(default: LevelFilter, verbosity: usize) -> LevelFilter {
         level_from_verbosity(default, verbosity)
     }
+
+    #[test_case(LevelFilter::Off => Level::Error)]
+    #[test_case(LevelFilter::Warn => Level::Error)]
+    #[test_case(LevelFilter::Info => Level::Info)]
+    #[test_case(LevelFilter::Trace => Level::Trace)]
+    fn test_verbosity_from_level(level: LevelFilter) -> Level {
+        verbosity_from_level(level)
+    }
 }
@@ -0,0 +1,88 @@
+use crate::graph::*;
+use rand::Rng;
+
+/// Generates a directed scale-free graph on n nodes via preferential attachment. The first
+/// m + 1 nodes form a directed cycle; every further node attaches m edges to distinct earlier
+/// nodes, which are chosen with probability proportional to their current total degree. Each
+/// edge points towards the older node with probability 1/2 and towards the new node otherwise.
+///
+/// The resulting graph has exactly (m + 1) + (n - m - 1) * m edges if n > m + 1 and m > 0.
+/// ** Panics if m > 0 and n <= m **
+pub fn generate_barabasi_albert<G, R>(rng: &mut R, n: Node, m: Node) -> G
+where
+    R: Rng,
+    G: GraphNew + GraphEdgeEditing,
+{
+    let mut result = G::new(n as usize);
+    if m == 0 || n == 0 {
+        return result;
+    }
+    assert!(n > m, "Need more than m nodes");
+
+    let seed_size = m + 1;
+    // every node appears once per incident edge, so drawing uniformly from this list is
+    // equivalent to drawing proportional to the degree
+    let mut endpoints: Vec<Node> = Vec::with_capacity(2 * (n * m) as usize);
+    for u in 0..seed_size {
+        let v = (u + 1) % seed_size;
+        result.add_edge(u, v);
+        endpoints.extend([u, v]);
+    }
+
+    let mut targets = Vec::with_capacity(m as usize);
+    for u in seed_size..n {
+        targets.clear();
+        while targets.len() < m as usize {
+            let v = endpoints[rng.gen_range(0..endpoints.len())];
+            if !targets.contains(&v) {
+                targets.push(v);
+            }
+        }
+
+        for &v in &targets {
+            if rng.gen_bool(0.5) {
+                result.add_edge(u, v);
+            } else {
+                result.add_edge(v, u);
+            }
+            endpoints.extend([u, v]);
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
+
+    #[test]
+    fn test_barabasi_albert() {
+        let rng = &mut Pcg64Mcg::seed_from_u64(3);
+
+        let mut max_degrees = Vec::new();
+        for n in [100, 1000, 10000] {
+            let m = 3;
+            let graph: AdjArrayIn = generate_barabasi_albert(rng, n, m);
+            assert_eq!(graph.number_of_nodes(), n);
+            assert_eq!(graph.number_of_edges(), (m + 1 + (n - m - 1) * m) as usize);
+            assert!(graph.vertices().all(|u| !graph.has_edge(u, u)));
+
+            let max_degree = graph
+                .vertices()
+                .map(|u| graph.total_degree(u))
+                .max()
+                .unwrap();
+            max_degrees.push(max_degree);
+        }
+
+        // hubs grow with n and are far above the average degree of 2m
+        assert!(max_degrees.windows(2).all(|w| w[0] < w[1]));
+        assert!(max_degrees[2] > 10 * 2 * 3);
+
+        let graph: AdjArrayIn = generate_barabasi_albert(rng, 10, 0);
+        assert_eq!(graph.number_of_edges(), 0);
+    }
+}
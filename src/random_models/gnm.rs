@@ -0,0 +1,78 @@
+use crate::graph::*;
+use rand::seq::index;
+use rand::Rng;
+
+/// Generates a uniform random graph with n nodes and exactly m distinct directed edges. If
+/// `self_loops` is false, edges (u, u) are excluded.
+/// ** Panics if m exceeds the number of possible edges, i.e. n*(n-1) or n*n with self-loops **
+pub fn generate_gnm<G, R>(rng: &mut R, n: Node, m: usize, self_loops: bool) -> G
+where
+    R: Rng,
+    G: GraphNew + GraphEdgeEditing,
+{
+    let n_usize = n as usize;
+    let row_len = if self_loops {
+        n_usize
+    } else {
+        n_usize.saturating_sub(1)
+    };
+    let max_edges = n_usize * row_len;
+    assert!(
+        m <= max_edges,
+        "Cannot place {} edges into a graph with {} nodes",
+        m,
+        n
+    );
+
+    let mut result = G::new(n_usize);
+
+    // each index encodes the edge (x / row_len, x % row_len); without self-loops the target is
+    // shifted by one if it is not smaller than the source
+    for x in index::sample(rng, max_edges, m) {
+        let u = x / row_len;
+        let mut v = x % row_len;
+        if !self_loops && v >= u {
+            v += 1;
+        }
+        result.add_edge(u as Node, v as Node);
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
+
+    #[test]
+    fn test_gnm() {
+        let mut rng = Pcg64Mcg::seed_from_u64(1);
+        for (n, m) in [
+            (0, 0),
+            (1, 0),
+            (2, 2),
+            (10, 0),
+            (10, 45),
+            (10, 90),
+            (100, 1000),
+        ] {
+            let graph: AdjArray = generate_gnm(&mut rng, n, m, false);
+            assert_eq!(graph.number_of_nodes(), n);
+            assert_eq!(graph.number_of_edges(), m);
+            assert!(graph.vertices().all(|u| !graph.has_edge(u, u)));
+        }
+
+        let graph: AdjArray = generate_gnm(&mut rng, 10, 100, true);
+        assert_eq!(graph.number_of_edges(), 100);
+        assert!(graph.vertices().all(|u| graph.has_edge(u, u)));
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_gnm_too_many_edges() {
+        let mut rng = Pcg64Mcg::seed_from_u64(1);
+        let _: AdjArray = generate_gnm(&mut rng, 10, 91, false);
+    }
+}
@@ -1,4 +1,5 @@
 use crate::graph::*;
+use rand::seq::SliceRandom;
 use rand::Rng;
 use rand_distr::Geometric;
 
@@ -74,10 +75,35 @@ where
     result
 }
 
+/// Generates a random DAG: the nodes are put into a random topological order and each of the
+/// n(n-1)/2 forward edges exists independently with probability p
+pub fn generate_random_dag<G, R>(rng: &mut R, n: Node, p: f64) -> G
+where
+    R: Rng,
+    G: GraphNew + GraphEdgeEditing,
+{
+    let mut result = G::new(n as usize);
+
+    let mut order: Vec<Node> = (0..n).collect();
+    order.shuffle(rng);
+
+    for (i, &u) in order.iter().enumerate() {
+        let succs: Vec<i64> = BernoulliSamplingRange::new(rng, i as i64 + 1, n as i64, p).collect();
+        for j in succs {
+            result.add_edge(u, order[j as usize]);
+        }
+    }
+
+    result
+}
+
 #[cfg(test)]
 mod test {
-    use crate::graph::{AdjListMatrix, GraphOrder};
-    use crate::random_models::gnp::{generate_gnp, BernoulliSamplingRange};
+    use crate::exact::branch_and_bound_matrix::branch_and_bound_matrix;
+    use crate::graph::{AdjArray, AdjListMatrix, GraphOrder, Traversal};
+    use crate::random_models::gnp::{generate_gnp, generate_random_dag, BernoulliSamplingRange};
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
 
     #[test]
     fn test_bernoulli_range() {
@@ -153,4 +179,21 @@ mod test {
             assert!((0.75 * expected..1.25 * expected).contains(&mean_edges));
         }
     }
+
+    #[test]
+    fn test_random_dag() {
+        for seed in 0..10 {
+            let rng = &mut Pcg64Mcg::seed_from_u64(seed);
+            let graph: AdjArray = generate_random_dag(rng, 50, 0.2);
+            assert_eq!(graph.number_of_nodes(), 50);
+            assert!(graph.is_acyclic());
+            assert!(branch_and_bound_matrix(&graph, None).unwrap().is_empty());
+        }
+
+        // p = 1 yields a transitive tournament
+        let rng = &mut Pcg64Mcg::seed_from_u64(0);
+        let graph: AdjArray = generate_random_dag(rng, 20, 1.0);
+        assert_eq!(graph.number_of_edges(), 20 * 19 / 2);
+        assert!(graph.is_acyclic());
+    }
 }
@@ -0,0 +1,60 @@
+use crate::graph::*;
+use rand::Rng;
+
+/// Generates a `rows` x `cols` lattice in which each of the rows*(cols-1) + (rows-1)*cols
+/// edges between horizontally or vertically adjacent nodes is oriented uniformly at random.
+/// The node in row r and column c has id r*cols + c.
+pub fn generate_oriented_grid<G, R>(rng: &mut R, rows: Node, cols: Node) -> G
+where
+    R: Rng,
+    G: GraphNew + GraphEdgeEditing,
+{
+    let mut result = G::new((rows * cols) as usize);
+    let mut add_oriented = |u: Node, v: Node| {
+        if rng.gen_bool(0.5) {
+            result.add_edge(u, v);
+        } else {
+            result.add_edge(v, u);
+        }
+    };
+
+    for r in 0..rows {
+        for c in 0..cols {
+            let u = r * cols + c;
+            if c + 1 < cols {
+                add_oriented(u, u + 1);
+            }
+            if r + 1 < rows {
+                add_oriented(u, u + cols);
+            }
+        }
+    }
+
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
+
+    #[test]
+    fn test_oriented_grid() {
+        let mut rng = Pcg64Mcg::seed_from_u64(1);
+        for (rows, cols) in [(0, 0), (0, 5), (1, 1), (1, 5), (4, 1), (3, 4), (20, 30)] {
+            let graph: AdjArray = generate_oriented_grid(&mut rng, rows, cols);
+            assert_eq!(graph.number_of_nodes(), rows * cols);
+            assert_eq!(
+                graph.number_of_edges(),
+                (rows * cols.saturating_sub(1) + rows.saturating_sub(1) * cols) as usize
+            );
+
+            for (u, v) in graph.edges_iter() {
+                let (lo, hi) = (u.min(v), u.max(v));
+                assert!((hi == lo + 1 && hi % cols != 0) || hi == lo + cols);
+                assert!(!graph.has_edge(v, u));
+            }
+        }
+    }
+}
@@ -0,0 +1,90 @@
+use crate::graph::*;
+use rand::Rng;
+
+/// Number of times the matching is restarted before [`generate_k_regular`] gives up
+const MAX_ATTEMPTS: usize = 100;
+
+/// Generates a random simple (i.e. without self-loops and multi-edges) graph over n nodes in which
+/// every node has in- and out-degree exactly k.
+///
+/// Following the configuration model, each node receives k in-stubs. The out-stubs of the nodes
+/// are then matched one after another to a uniformly chosen remaining in-stub that neither yields
+/// a self-loop nor a multi-edge. If no such in-stub is left, the matching is restarted. Returns
+/// `None` if k >= n or if no matching was completed within a bounded number of restarts.
+pub fn generate_k_regular<G, R>(rng: &mut R, n: Node, k: Node) -> Option<G>
+where
+    R: Rng,
+    G: GraphNew + GraphEdgeEditing,
+{
+    if n > 0 && k >= n {
+        return None;
+    }
+
+    'attempt: for _ in 0..MAX_ATTEMPTS {
+        let mut in_stubs: Vec<Node> = (0..n).flat_map(|v| (0..k).map(move |_| v)).collect();
+        let mut edges = Vec::with_capacity(in_stubs.len());
+        let mut targets = Vec::with_capacity(k as usize);
+
+        for u in 0..n {
+            targets.clear();
+            for _ in 0..k {
+                let admissible = |v: Node, targets: &[Node]| v != u && !targets.contains(&v);
+
+                // random probing is fast while most in-stubs are admissible; fall back to a scan
+                let mut index = (0..8)
+                    .map(|_| rng.gen_range(0..in_stubs.len()))
+                    .find(|&i| admissible(in_stubs[i], &targets));
+                if index.is_none() {
+                    let candidates: Vec<usize> = (0..in_stubs.len())
+                        .filter(|&i| admissible(in_stubs[i], &targets))
+                        .collect();
+                    if candidates.is_empty() {
+                        continue 'attempt;
+                    }
+                    index = Some(candidates[rng.gen_range(0..candidates.len())]);
+                }
+
+                let v = in_stubs.swap_remove(index.unwrap());
+                targets.push(v);
+                edges.push((u, v));
+            }
+        }
+
+        let mut result = G::new(n as usize);
+        for (u, v) in edges {
+            result.add_edge(u, v);
+        }
+        return Some(result);
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
+
+    #[test]
+    fn test_k_regular() {
+        let mut rng = Pcg64Mcg::seed_from_u64(1);
+        for (n, k) in [(0, 0), (1, 0), (2, 1), (5, 4), (10, 3), (100, 5), (500, 10)] {
+            let graph: AdjArrayIn = generate_k_regular(&mut rng, n, k).unwrap();
+            assert_eq!(graph.number_of_nodes(), n);
+            assert_eq!(graph.number_of_edges(), (n * k) as usize);
+            for u in graph.vertices() {
+                assert!(!graph.has_edge(u, u));
+                assert_eq!(graph.out_degree(u), k);
+                assert_eq!(graph.in_degree(u), k);
+            }
+        }
+    }
+
+    #[test]
+    fn test_k_regular_impossible() {
+        let mut rng = Pcg64Mcg::seed_from_u64(1);
+        assert!(generate_k_regular::<AdjArray, _>(&mut rng, 5, 5).is_none());
+        assert!(generate_k_regular::<AdjArray, _>(&mut rng, 1, 1).is_none());
+    }
+}
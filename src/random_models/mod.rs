@@ -1,2 +1,8 @@
+pub mod barabasi_albert;
+pub mod gnm;
 pub mod gnp;
+pub mod grid;
+pub mod k_regular;
 pub mod planted_cycles;
+pub mod planted_dfvs;
+pub mod tournament;
@@ -0,0 +1,102 @@
+use crate::graph::*;
+use rand::seq::SliceRandom;
+use rand::Rng;
+
+/// Generates a random graph over n nodes whose minimum DFVS is a planted set of k hub nodes.
+///
+/// The non-hub nodes are put into a random topological order. Every hub gets two private
+/// partners among them with which it forms a 2-cycle; since these 2-cycles are disjoint for
+/// different hubs, any DFVS contains at least k nodes. Additionally, `extra_edges` random edges
+/// are added, each either incident to a hub or pointing forward in the topological order, so the
+/// hubs still hit every cycle.
+///
+/// Returns the graph and the sorted planted DFVS.
+/// ** Panics if n < 3k or if there is not enough room for the extra edges **
+pub fn generate_planted_dfvs<G, R>(
+    rng: &mut R,
+    n: Node,
+    k: Node,
+    extra_edges: usize,
+) -> (G, Vec<Node>)
+where
+    R: Rng,
+    G: GraphNew + GraphEdgeEditing + GraphOrder,
+{
+    assert!(n >= 3 * k, "Every hub requires two private partners");
+    // non-hubs may only be connected forward in the topological order
+    let (n_usize, non_hubs) = (n as usize, (n - k) as usize);
+    let max_edges = n_usize * n_usize.saturating_sub(1) - non_hubs * non_hubs.saturating_sub(1) / 2;
+    assert!(
+        4 * k as usize + extra_edges <= max_edges,
+        "Too many extra edges requested"
+    );
+
+    let mut nodes: Vec<Node> = (0..n).collect();
+    nodes.shuffle(rng);
+    let (hubs, order) = nodes.split_at(k as usize);
+
+    let mut rank = vec![None; n as usize];
+    for (i, &u) in order.iter().enumerate() {
+        rank[u as usize] = Some(i);
+    }
+
+    let mut result = G::new(n as usize);
+    for (i, &hub) in hubs.iter().enumerate() {
+        for &partner in &order[2 * i..2 * i + 2] {
+            result.add_edge(hub, partner);
+            result.add_edge(partner, hub);
+        }
+    }
+
+    let target_m = result.number_of_edges() + extra_edges;
+    while result.number_of_edges() < target_m {
+        let u = rng.gen_range(0..n);
+        let v = rng.gen_range(0..n);
+        if u == v {
+            continue;
+        }
+
+        // edges between non-hubs must respect the topological order
+        let (u, v) = match (rank[u as usize], rank[v as usize]) {
+            (Some(ru), Some(rv)) if ru > rv => (v, u),
+            _ => (u, v),
+        };
+        result.try_add_edge(u, v);
+    }
+
+    let mut hubs = hubs.to_vec();
+    hubs.sort_unstable();
+    (result, hubs)
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use crate::bitset::BitSet;
+    use crate::exact::branch_and_bound_matrix::branch_and_bound_matrix;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
+
+    #[test]
+    fn test_planted_dfvs() {
+        let mut rng = Pcg64Mcg::seed_from_u64(5);
+        for (n, k, extra_edges) in [(0, 0, 0), (3, 1, 0), (10, 2, 20), (20, 5, 40), (40, 8, 100)] {
+            let (graph, planted): (AdjArray, _) =
+                generate_planted_dfvs(&mut rng, n, k, extra_edges);
+            assert_eq!(graph.number_of_nodes(), n);
+            assert_eq!(graph.number_of_edges(), 4 * k as usize + extra_edges);
+            assert_eq!(planted.len(), k as usize);
+
+            let remaining = graph
+                .vertex_induced(&BitSet::new_all_set_but(
+                    graph.len(),
+                    planted.iter().copied(),
+                ))
+                .0;
+            assert!(remaining.is_acyclic());
+
+            let solution = branch_and_bound_matrix(&graph, None).unwrap();
+            assert_eq!(solution.len(), k as usize, "planted DFVS is not minimum");
+        }
+    }
+}
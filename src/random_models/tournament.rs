@@ -0,0 +1,52 @@
+use crate::graph::*;
+use rand::Rng;
+
+/// Generates a random tournament over n nodes, i.e. for each pair of distinct nodes {u, v}
+/// exactly one of the edges (u, v) and (v, u) exists, each with probability 1/2. Hence, the graph
+/// has no 2-cycles and n*(n-1)/2 edges.
+pub fn generate_tournament<G, R>(rng: &mut R, n: Node) -> G
+where
+    R: Rng,
+    G: GraphNew + GraphEdgeEditing,
+{
+    let mut result = G::new(n as usize);
+    for u in 0..n {
+        for v in u + 1..n {
+            if rng.gen_bool(0.5) {
+                result.add_edge(u, v);
+            } else {
+                result.add_edge(v, u);
+            }
+        }
+    }
+    result
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+    use rand::SeedableRng;
+    use rand_pcg::Pcg64Mcg;
+
+    #[test]
+    fn test_tournament() {
+        let mut rng = Pcg64Mcg::seed_from_u64(1);
+        for n in [0, 1, 2, 10, 50] {
+            let graph: AdjArray = generate_tournament(&mut rng, n);
+            assert_eq!(graph.number_of_nodes(), n);
+            assert_eq!(
+                graph.number_of_edges(),
+                (n * n.saturating_sub(1) / 2) as usize
+            );
+            for u in graph.vertices() {
+                assert!(!graph.has_edge(u, u));
+                for v in u + 1..n {
+                    assert!(graph.has_edge(u, v) != graph.has_edge(v, u));
+                }
+            }
+        }
+
+        let graph: AdjArray = generate_tournament(&mut rng, 50);
+        assert!(graph.is_strongly_connected());
+    }
+}
@@ -1,7 +1,17 @@
+use crate::graph::Node;
+use crate::log::{log_if, Level};
 use core::sync::atomic::AtomicBool;
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::{Path, PathBuf};
 use std::sync::atomic::Ordering;
+use std::sync::{Arc, Condvar, Mutex};
+use std::thread::JoinHandle;
+use std::time::Duration;
 
-static SIGINT: AtomicBool = AtomicBool::new(false);
+/// Set once a termination signal was received (see [`received_ctrl_c`]); can be passed as cancel
+/// flag, e.g. to `branch_and_bound_matrix_cancellable`
+pub static SIGINT: AtomicBool = AtomicBool::new(false);
 
 pub fn received_ctrl_c() -> bool {
     SIGINT.load(Ordering::SeqCst)
@@ -14,3 +24,153 @@ pub fn initialize() {
     })
     .expect("Error setting Ctrl-C handler");
 }
+
+/// Installs a handler that, on SIGINT/SIGTERM, writes the solution stored in `shared` to stdout in
+/// the PACE format and exits the process. Solvers should keep `shared` updated with their best-known
+/// solution, e.g. by running via [`crate::algorithm::IterativeAlgorithm::run_until_timeout_sharing`].
+/// This replaces [`initialize`], i.e. at most one of both may be called.
+#[cfg(feature = "signal-handling")]
+pub fn install_solution_dump_handler(shared: Arc<Mutex<Option<Vec<Node>>>>) {
+    ctrlc::set_handler(move || {
+        SIGINT.store(true, Ordering::SeqCst);
+        let stdout = io::stdout();
+        let code = match dump_solution(&shared, stdout.lock()) {
+            Ok(()) => 0,
+            Err(_) => 1,
+        };
+        std::process::exit(code);
+    })
+    .expect("Error setting Ctrl-C handler");
+}
+
+/// Writes the solution stored in `shared` (if any) in the PACE format, i.e. one 1-based node id
+/// per line. This is the action carried out by the handler of [`install_solution_dump_handler`].
+pub fn dump_solution<W: Write>(shared: &Mutex<Option<Vec<Node>>>, mut writer: W) -> io::Result<()> {
+    // a solver panicking while holding the lock must not prevent the output of its last solution
+    let solution = shared.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(solution) = solution.as_ref() {
+        for &u in solution {
+            writeln!(writer, "{}", u + 1)?;
+        }
+    }
+    writer.flush()
+}
+
+/// Stops the checkpointing thread started by [`start_checkpointing`] when dropped
+pub struct CheckpointGuard {
+    stop: Arc<(Mutex<bool>, Condvar)>,
+    handle: Option<JoinHandle<()>>,
+}
+
+impl Drop for CheckpointGuard {
+    fn drop(&mut self) {
+        let (stopped, condvar) = &*self.stop;
+        *stopped.lock().unwrap() = true;
+        condvar.notify_all();
+        if let Some(handle) = self.handle.take() {
+            let _ = handle.join();
+        }
+    }
+}
+
+/// Spawns a thread that writes the solution stored in `shared` to `path` in the PACE format (see
+/// [`dump_solution`]) every `interval`, so the best-known solution of a long run survives a crash.
+/// The file is only rewritten if the solution changed; a final checkpoint is written once the
+/// returned guard is dropped. To never leave a partial file behind, the solution is first written
+/// to a temporary file next to `path` which is then renamed.
+pub fn start_checkpointing(
+    path: impl Into<PathBuf>,
+    interval: Duration,
+    shared: Arc<Mutex<Option<Vec<Node>>>>,
+) -> CheckpointGuard {
+    let path = path.into();
+    let stop = Arc::new((Mutex::new(false), Condvar::new()));
+
+    let thread_stop = stop.clone();
+    let handle = std::thread::spawn(move || {
+        let (stopped, condvar) = &*thread_stop;
+        let mut last_written: Option<Vec<Node>> = None;
+        loop {
+            let is_stopped = {
+                let guard = stopped.lock().unwrap();
+                let (guard, _) = condvar
+                    .wait_timeout_while(guard, interval, |stopped| !*stopped)
+                    .unwrap();
+                *guard
+            };
+
+            let current = shared.lock().unwrap_or_else(|e| e.into_inner()).clone();
+            if current.is_some() && current != last_written {
+                match write_checkpoint(&path, current.as_deref().unwrap()) {
+                    Ok(()) => last_written = current,
+                    Err(e) => log_if!(Level::Error, || format!(
+                        "Cannot write checkpoint to {}: {}",
+                        path.display(),
+                        e
+                    )),
+                }
+            }
+
+            if is_stopped {
+                break;
+            }
+        }
+    });
+
+    CheckpointGuard {
+        stop,
+        handle: Some(handle),
+    }
+}
+
+fn write_checkpoint(path: &Path, solution: &[Node]) -> io::Result<()> {
+    let mut tmp_path = path.as_os_str().to_owned();
+    tmp_path.push(".tmp");
+    {
+        let mut writer = BufWriter::new(File::create(&tmp_path)?);
+        for &u in solution {
+            writeln!(writer, "{}", u + 1)?;
+        }
+        writer.flush()?;
+    }
+    std::fs::rename(&tmp_path, path)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dump_solution() {
+        let shared = Mutex::new(None);
+        let mut output = Vec::new();
+        super::dump_solution(&shared, &mut output).unwrap();
+        assert!(output.is_empty());
+
+        *shared.lock().unwrap() = Some(vec![0, 4, 2]);
+        super::dump_solution(&shared, &mut output).unwrap();
+        assert_eq!(String::from_utf8(output).unwrap(), "1\n5\n3\n");
+    }
+
+    #[cfg(feature = "tempfile")]
+    #[test]
+    fn checkpointing() {
+        let temp_dir = tempfile::TempDir::new().unwrap();
+        let path = temp_dir.path().join("checkpoint.sol");
+        let shared = Arc::new(Mutex::new(None));
+
+        let guard = start_checkpointing(&path, Duration::from_millis(1), shared.clone());
+        *shared.lock().unwrap() = Some(vec![3, 0]);
+        let deadline = std::time::Instant::now() + Duration::from_secs(10);
+        while !path.exists() {
+            assert!(std::time::Instant::now() < deadline);
+            std::thread::sleep(Duration::from_millis(1));
+        }
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "4\n1\n");
+
+        // the last solution is written when the guard is dropped
+        *shared.lock().unwrap() = Some(vec![1]);
+        drop(guard);
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "2\n");
+    }
+}